@@ -1,7 +1,9 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
 use hex::{FromHex, ToHex};
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::str::FromStr;
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq)]
@@ -13,7 +15,14 @@ pub enum IDError {
     InvalidLength,
 
     #[error("Failed to decode FeedID")]
-    DecodeError(#[from] hex::FromHexError),
+    DecodeError(#[cfg_attr(feature = "std", source)] hex::FromHexError),
+}
+
+// `FromHexError` only implements `Error` with `std`, so `#[from]` can't be used here
+impl From<hex::FromHexError> for IDError {
+    fn from(err: hex::FromHexError) -> Self {
+        IDError::DecodeError(err)
+    }
 }
 
 /// Represents a 32-byte identifier.
@@ -59,6 +68,7 @@ impl ID {
     ///
     /// let id = ID::from_hex_str("0x00016b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap();
     /// ```
+    #[must_use = "an invalid feed ID is only rejected if the result is checked"]
     pub fn from_hex_str(s: &str) -> Result<Self, IDError> {
         let s = s.trim();
 
@@ -76,6 +86,126 @@ impl ID {
         Ok(ID(bytes))
     }
 
+    /// Parses an `ID` from a hexadecimal string like `from_hex_str`, but with the "0x" prefix
+    /// optional, for systems that emit feed IDs without it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The string length after the optional prefix is not exactly 64 characters (32 bytes).
+    /// - The string contains invalid hexadecimal characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    ///
+    /// let prefixed = ID::from_hex_lenient("0x00016b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap();
+    /// let unprefixed = ID::from_hex_lenient("00016B4AA7E57CA7B68AE1BF45653F56B656FD3AA335EF7FAE696B663F1B8472").unwrap();
+    /// assert_eq!(prefixed, unprefixed);
+    /// ```
+    #[must_use = "an invalid feed ID is only rejected if the result is checked"]
+    pub fn from_hex_lenient(s: &str) -> Result<Self, IDError> {
+        let s = s.trim();
+        let hex_str = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+
+        if hex_str.len() != 64 {
+            return Err(IDError::InvalidLength);
+        }
+
+        let bytes = <[u8; 32]>::from_hex(hex_str)?;
+        Ok(ID(bytes))
+    }
+
+    /// Creates an `ID` from its raw 32 bytes. Every 32-byte value is a valid `ID`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    ///
+    /// const FEED_ID: ID = ID::from_bytes([0u8; 32]);
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        ID(bytes)
+    }
+
+    /// Parses an `ID` from a byte slice, which must be exactly 32 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IDError::InvalidLength` if the slice is not 32 bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    ///
+    /// let id = ID::from_slice(&[0u8; 32]).unwrap();
+    /// assert!(ID::from_slice(&[0u8; 31]).is_err());
+    /// ```
+    #[must_use = "an invalid feed ID is only rejected if the result is checked"]
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, IDError> {
+        let bytes = <[u8; 32]>::try_from(bytes).map_err(|_| IDError::InvalidLength)?;
+        Ok(ID(bytes))
+    }
+
+    /// Returns the report schema version of the feed, encoded in the first two bytes of the ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    ///
+    /// let id = ID::from_hex_str("0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap();
+    /// assert_eq!(id.version(), 3);
+    /// ```
+    pub fn version(&self) -> u16 {
+        u16::from_be_bytes([self.0[0], self.0[1]])
+    }
+
+    /// Creates an `ID` from a report schema version and the 30 bytes that follow it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    ///
+    /// let id = ID::with_version(3, [0xab; 30]);
+    /// assert_eq!(id.version(), 3);
+    /// assert_eq!(id.body(), [0xab; 30]);
+    /// ```
+    pub fn with_version(version: u16, body: [u8; 30]) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[..2].copy_from_slice(&version.to_be_bytes());
+        bytes[2..].copy_from_slice(&body);
+        ID(bytes)
+    }
+
+    /// Returns the 30 bytes of the ID that follow the report schema version.
+    pub fn body(&self) -> [u8; 30] {
+        let mut body = [0u8; 30];
+        body.copy_from_slice(&self.0[2..]);
+        body
+    }
+
+    /// Returns the raw 32 bytes of the ID, e.g. to hash it or pass it to a contract call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    ///
+    /// let id = ID::from_bytes([7u8; 32]);
+    /// assert_eq!(id.as_bytes(), &[7u8; 32]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
     /// Returns the hexadecimal string representation prefixed with "0x".
     ///
     /// # Returns
@@ -123,6 +253,36 @@ impl FromStr for ID {
     }
 }
 
+impl From<[u8; 32]> for ID {
+    fn from(bytes: [u8; 32]) -> Self {
+        ID::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&str> for ID {
+    type Error = IDError;
+
+    /// Parses an `ID` from a string using `from_hex_str`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        ID::from_hex_str(s)
+    }
+}
+
+impl TryFrom<&[u8]> for ID {
+    type Error = IDError;
+
+    /// Parses an `ID` from a byte slice using `from_slice`.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        ID::from_slice(bytes)
+    }
+}
+
+impl AsRef<[u8]> for ID {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl fmt::Display for ID {
     /// Formats the `ID` using its hexadecimal string representation.
     ///
@@ -209,15 +369,15 @@ mod tests {
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V2_FEED_ID: ID = ID([
-        00, 02, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 2, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V3_FEED_ID: ID = ID([
-        00, 03, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 3, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V4_FEED_ID: ID = ID([
-        00, 04, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 4, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
 
@@ -268,10 +428,109 @@ mod tests {
         assert!(matches!(result, Err(IDError::InvalidLength)));
     }
 
+    #[test]
+    fn test_revert_if_wrong_length_with_valid_prefix() {
+        // One hex character short, and one byte too long
+        let short = &V1_FEED_ID_STR[..V1_FEED_ID_STR.len() - 1];
+        let long = format!("{}00", V1_FEED_ID_STR);
+
+        assert_eq!(ID::from_hex_str(short), Err(IDError::InvalidLength));
+        assert_eq!(ID::from_hex_str(&long), Err(IDError::InvalidLength));
+    }
+
+    #[test]
+    fn test_from_hex_lenient() {
+        let unprefixed = &V1_FEED_ID_STR[2..];
+
+        assert_eq!(ID::from_hex_lenient(V1_FEED_ID_STR), Ok(V1_FEED_ID));
+        assert_eq!(ID::from_hex_lenient(unprefixed), Ok(V1_FEED_ID));
+        assert_eq!(
+            ID::from_hex_lenient(&V1_FEED_ID_STR.to_uppercase()),
+            Ok(V1_FEED_ID)
+        );
+        assert_eq!(
+            ID::from_hex_lenient(&unprefixed.to_uppercase()),
+            Ok(V1_FEED_ID)
+        );
+
+        assert_eq!(
+            ID::from_hex_lenient(&unprefixed[1..]),
+            Err(IDError::InvalidLength)
+        );
+        assert_eq!(ID::from_hex_lenient("0x"), Err(IDError::InvalidLength));
+        assert!(matches!(
+            ID::from_hex_lenient(&"Z".repeat(64)),
+            Err(IDError::DecodeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(ID::try_from(V1_FEED_ID_STR), Ok(V1_FEED_ID));
+        assert_eq!(ID::try_from(&V1_FEED_ID.0[..]), Ok(V1_FEED_ID));
+        assert_eq!(ID::from(V1_FEED_ID.0), V1_FEED_ID);
+
+        assert_eq!(
+            ID::try_from(&V1_FEED_ID_STR[2..]),
+            Err(IDError::MissingPrefix)
+        );
+        assert_eq!(ID::try_from(&[0u8; 31][..]), Err(IDError::InvalidLength));
+        assert_eq!(ID::try_from(&[0u8; 33][..]), Err(IDError::InvalidLength));
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        assert_eq!(V1_FEED_ID.as_bytes(), &V1_FEED_ID.0);
+
+        let bytes: &[u8] = V1_FEED_ID.as_ref();
+        assert_eq!(bytes, &V1_FEED_ID.0[..]);
+        assert_eq!(ID::try_from(bytes), Ok(V1_FEED_ID));
+    }
+
     #[test]
     fn test_revert_if_failed_to_decode() {
         let hex_str = "0xZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ";
         let result = ID::from_hex_str(hex_str);
         assert!(matches!(result, Err(IDError::DecodeError(_))));
     }
+
+    #[test]
+    fn test_version() {
+        assert_eq!(V1_FEED_ID.version(), 1);
+        assert_eq!(V2_FEED_ID.version(), 2);
+        assert_eq!(V3_FEED_ID.version(), 3);
+        assert_eq!(V4_FEED_ID.version(), 4);
+
+        for version in 1..=13u16 {
+            let mut bytes = V1_FEED_ID.0;
+            bytes[..2].copy_from_slice(&version.to_be_bytes());
+            assert_eq!(ID(bytes).version(), version);
+
+            let hex_str = format!("0x{:04x}{}", version, &V1_FEED_ID_STR[6..]);
+            assert_eq!(ID::from_hex_str(&hex_str).unwrap().version(), version);
+        }
+    }
+
+    #[test]
+    fn test_with_version() {
+        let body = V3_FEED_ID.body();
+        assert_eq!(ID::with_version(3, body), V3_FEED_ID);
+        assert_eq!(ID::with_version(3, body).to_hex_string(), V3_FEED_ID_STR);
+
+        // Feeds of the same asset share the body across schema versions
+        assert_eq!(V1_FEED_ID.body(), body);
+        assert_eq!(ID::with_version(4, body), V4_FEED_ID);
+
+        let id = ID::with_version(0x0102, [0xff; 30]);
+        assert_eq!(id.version(), 0x0102);
+        assert_eq!(id.0[..2], [0x01, 0x02]);
+        assert_eq!(id.body(), [0xff; 30]);
+    }
+
+    #[test]
+    fn test_version_is_big_endian() {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 0x01;
+        assert_eq!(ID(bytes).version(), 0x0100);
+    }
 }
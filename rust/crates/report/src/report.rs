@@ -1,5 +1,16 @@
+#[cfg(feature = "std")]
+pub mod archive;
 pub mod base;
+pub mod compact;
+#[cfg(feature = "std")]
 pub mod compress;
+pub mod decimal;
+pub mod market_status;
+pub mod ripcord;
+pub mod schema;
+mod serde_fields;
+#[cfg(feature = "std")]
+pub mod time;
 pub mod v1;
 pub mod v10;
 pub mod v11;
@@ -15,9 +26,28 @@ pub mod v8;
 pub mod v9;
 
 use base::{ReportBase, ReportError};
+use v1::ReportDataV1;
+use v10::ReportDataV10;
+use v11::ReportDataV11;
+use v12::ReportDataV12;
+use v13::ReportDataV13;
+use v2::ReportDataV2;
+use v3::ReportDataV3;
+use v4::ReportDataV4;
+use v5::ReportDataV5;
+use v6::ReportDataV6;
+use v7::ReportDataV7;
+use v8::ReportDataV8;
+use v9::ReportDataV9;
 
 use crate::feed_id::ID;
 
+use num_bigint::BigInt;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Represents a report that will be returned from the Data Streams DON.
@@ -45,7 +75,6 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Report {
     #[serde(rename = "feedID")]
-    // pub feed_id: [u8; 32],
     pub feed_id: ID,
 
     #[serde(rename = "validFromTimestamp")]
@@ -58,6 +87,560 @@ pub struct Report {
     pub full_report: String,
 }
 
+impl Report {
+    /// Returns a `ReportBuilder` that encodes `report_data` into a `Report`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    /// use chainlink_data_streams_report::report::{v2::ReportDataV2, Report, ReportData};
+    /// use num_bigint::BigInt;
+    ///
+    /// let report_data = ReportDataV2 {
+    ///     feed_id: ID::from_hex_str("0x00026b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap(),
+    ///     valid_from_timestamp: 1718885772,
+    ///     observations_timestamp: 1718885772,
+    ///     native_fee: BigInt::from(10),
+    ///     link_fee: BigInt::from(10),
+    ///     expires_at: 1718885872,
+    ///     benchmark_price: BigInt::from(100),
+    /// };
+    ///
+    /// let report = Report::builder(ReportData::V2(report_data)).build().unwrap();
+    /// assert_eq!(report.observations_timestamp, 1718885772);
+    /// ```
+    pub fn builder(report_data: ReportData) -> ReportBuilder {
+        ReportBuilder {
+            report_data,
+            report_context: [[0u8; 32]; 3],
+        }
+    }
+
+    /// Returns the raw `full_report` payload, ready for [`decode_full_report`].
+    ///
+    /// `full_report` may be hex encoded with or without the "0x" prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FromHexError` if `full_report` is not valid hex.
+    pub fn full_report_bytes(&self) -> Result<Vec<u8>, hex::FromHexError> {
+        let full_report = self
+            .full_report
+            .strip_prefix("0x")
+            .unwrap_or(&self.full_report);
+
+        hex::decode(full_report)
+    }
+
+    /// Decodes `full_report` into the typed report data matching the feed version.
+    ///
+    /// The version is read from the first two bytes of `feed_id`. `full_report` may be
+    /// hex encoded with or without the "0x" prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReportError` if `full_report` is not valid hex, the payload cannot be decoded,
+    /// or the feed version is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::report::{Report, ReportData};
+    ///
+    /// fn print_price(report: &Report) {
+    ///     // No need to pick a `ReportDataVn` decoder or to split `full_report` by hand
+    ///     match report.decode_typed() {
+    ///         Ok(ReportData::V3(data)) => println!("v3 benchmark price: {}", data.benchmark_price),
+    ///         Ok(data) => println!("v{} price: {}", data.version(), data.price()),
+    ///         Err(e) => eprintln!("Failed to decode report for {}: {}", report.feed_id, e),
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_typed(&self) -> Result<ReportData, ReportError> {
+        let payload = self
+            .full_report_bytes()
+            .map_err(|_| ReportError::ParseError("full_report"))?;
+
+        let (_report_context, report_blob) = decode_full_report(&payload)?;
+
+        ReportData::decode_for(self.feed_id, &report_blob)
+    }
+}
+
+/// Decodes the `full_report` of each report with [`Report::decode_typed`], e.g. for the reports
+/// returned by a bulk or page query.
+///
+/// Each report is decoded on its own, so a report that fails to decode only fails its own entry.
+/// The results are in the same order as `reports`.
+pub fn decode_reports(reports: &[Report]) -> Vec<Result<ReportData, ReportError>> {
+    reports.iter().map(Report::decode_typed).collect()
+}
+
+/// Matches every schema, binding the inner report data to `$r`.
+macro_rules! every_schema {
+    ($report_data:expr, |$r:ident| $expr:expr) => {
+        match $report_data {
+            ReportData::V1($r) => $expr,
+            ReportData::V2($r) => $expr,
+            ReportData::V3($r) => $expr,
+            ReportData::V4($r) => $expr,
+            ReportData::V5($r) => $expr,
+            ReportData::V6($r) => $expr,
+            ReportData::V7($r) => $expr,
+            ReportData::V8($r) => $expr,
+            ReportData::V9($r) => $expr,
+            ReportData::V10($r) => $expr,
+            ReportData::V11($r) => $expr,
+            ReportData::V12($r) => $expr,
+            ReportData::V13($r) => $expr,
+        }
+    };
+}
+
+/// Matches the schemas from v2 onwards, yielding `Some($expr)`, and `None` for v1.
+macro_rules! v2_onwards {
+    ($report_data:expr, |$r:ident| $expr:expr) => {
+        match $report_data {
+            ReportData::V1(_) => None,
+            ReportData::V2($r) => Some($expr),
+            ReportData::V3($r) => Some($expr),
+            ReportData::V4($r) => Some($expr),
+            ReportData::V5($r) => Some($expr),
+            ReportData::V6($r) => Some($expr),
+            ReportData::V7($r) => Some($expr),
+            ReportData::V8($r) => Some($expr),
+            ReportData::V9($r) => Some($expr),
+            ReportData::V10($r) => Some($expr),
+            ReportData::V11($r) => Some($expr),
+            ReportData::V12($r) => Some($expr),
+            ReportData::V13($r) => Some($expr),
+        }
+    };
+}
+
+/// Version-specific report data, decoded from a report blob.
+///
+/// v1 reports are block-based: they carry no `valid_from_timestamp`, `native_fee`, `link_fee` or
+/// `expires_at`, so the accessors for those fields return `None` for v1. `feed_id` and
+/// `observations_timestamp` are defined for every schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportData {
+    V1(ReportDataV1),
+    V2(ReportDataV2),
+    V3(ReportDataV3),
+    V4(ReportDataV4),
+    V5(ReportDataV5),
+    V6(ReportDataV6),
+    V7(ReportDataV7),
+    V8(ReportDataV8),
+    V9(ReportDataV9),
+    V10(ReportDataV10),
+    V11(ReportDataV11),
+    V12(ReportDataV12),
+    V13(ReportDataV13),
+}
+
+impl ReportData {
+    /// Decodes `blob` with the decoder for `version`, or returns `None` if there is none.
+    fn dispatch(version: u16, blob: &[u8]) -> Option<Result<ReportData, ReportError>> {
+        let result = match version {
+            1 => ReportDataV1::decode(blob).map(ReportData::V1),
+            2 => ReportDataV2::decode(blob).map(ReportData::V2),
+            3 => ReportDataV3::decode(blob).map(ReportData::V3),
+            4 => ReportDataV4::decode(blob).map(ReportData::V4),
+            5 => ReportDataV5::decode(blob).map(ReportData::V5),
+            6 => ReportDataV6::decode(blob).map(ReportData::V6),
+            7 => ReportDataV7::decode(blob).map(ReportData::V7),
+            8 => ReportDataV8::decode(blob).map(ReportData::V8),
+            9 => ReportDataV9::decode(blob).map(ReportData::V9),
+            10 => ReportDataV10::decode(blob).map(ReportData::V10),
+            11 => ReportDataV11::decode(blob).map(ReportData::V11),
+            12 => ReportDataV12::decode(blob).map(ReportData::V12),
+            13 => ReportDataV13::decode(blob).map(ReportData::V13),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Decodes a report blob with the schema matching the version of `feed_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportError::UnsupportedVersion` if no schema exists for the feed version, or
+    /// another `ReportError` if the blob cannot be decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    /// use chainlink_data_streams_report::report::ReportData;
+    ///
+    /// let feed_id = ID::from_hex_str("0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap();
+    /// let blob = [0u8; 32];
+    ///
+    /// // A v3 feed is decoded as `ReportDataV3`, which needs more than one word
+    /// assert!(ReportData::decode_for(feed_id, &blob).is_err());
+    /// ```
+    pub fn decode_for(feed_id: ID, blob: &[u8]) -> Result<ReportData, ReportError> {
+        let version = feed_id.version();
+
+        ReportData::dispatch(version, blob).unwrap_or_else(|| {
+            Err(ReportError::UnsupportedVersion {
+                version,
+                feed_id: feed_id.to_hex_string(),
+            })
+        })
+    }
+
+    fn decode(version: u16, blob: &[u8]) -> Result<ReportData, ReportError> {
+        ReportData::dispatch(version, blob).unwrap_or_else(|| {
+            // Every schema starts with the feed ID, so report the blob's own
+            Err(ReportError::UnsupportedVersion {
+                version,
+                feed_id: format!("0x{}", hex::encode(&blob[..blob.len().min(32)])),
+            })
+        })
+    }
+
+    /// Decodes a report blob as the given schema version, tolerating near-identical schemas.
+    ///
+    /// Like the version-specific decoders, trailing data is ignored, so a blob of a schema that
+    /// extends `version` (e.g. a v10 blob decoded as v8) yields the shared prefix. If the blob is
+    /// too short for `version` but matches the schema it extends, it is decoded as that schema
+    /// instead (e.g. a v8 blob decoded as v10 yields `ReportData::V8`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReportError` if the blob cannot be decoded as `version` or its base schema.
+    pub fn decode_as(version: u16, blob: &[u8]) -> Result<ReportData, ReportError> {
+        let result = ReportData::decode(version, blob);
+
+        match (result, Self::base_version(version)) {
+            (Err(ReportError::DataTooShort(_)), Some(base)) => ReportData::decode_as(base, blob),
+            (result, _) => result,
+        }
+    }
+
+    /// Returns the schema version that `version` extends, if any.
+    fn base_version(version: u16) -> Option<u16> {
+        match version {
+            // v10 (tokenized assets) appends multiplier fields to v8 (RWA)
+            10 => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Returns the schema version of the report data, e.g. `3` for `ReportData::V3`.
+    pub fn version(&self) -> u16 {
+        match self {
+            ReportData::V1(_) => 1,
+            ReportData::V2(_) => 2,
+            ReportData::V3(_) => 3,
+            ReportData::V4(_) => 4,
+            ReportData::V5(_) => 5,
+            ReportData::V6(_) => 6,
+            ReportData::V7(_) => 7,
+            ReportData::V8(_) => 8,
+            ReportData::V9(_) => 9,
+            ReportData::V10(_) => 10,
+            ReportData::V11(_) => 11,
+            ReportData::V12(_) => 12,
+            ReportData::V13(_) => 13,
+        }
+    }
+
+    /// ABI-encodes the report data into a report blob.
+    pub fn abi_encode(&self) -> Result<Vec<u8>, ReportError> {
+        match self {
+            ReportData::V1(r) => r.abi_encode(),
+            ReportData::V2(r) => r.abi_encode(),
+            ReportData::V3(r) => r.abi_encode(),
+            ReportData::V4(r) => r.abi_encode(),
+            ReportData::V5(r) => r.abi_encode(),
+            ReportData::V6(r) => r.abi_encode(),
+            ReportData::V7(r) => r.abi_encode(),
+            ReportData::V8(r) => r.abi_encode(),
+            ReportData::V9(r) => r.abi_encode(),
+            ReportData::V10(r) => r.abi_encode(),
+            ReportData::V11(r) => r.abi_encode(),
+            ReportData::V12(r) => r.abi_encode(),
+            ReportData::V13(r) => r.abi_encode(),
+        }
+    }
+
+    /// Returns the primary price of the report, e.g. `benchmark_price` for v3 or `mid` for v11.
+    pub fn price(&self) -> &BigInt {
+        match self {
+            ReportData::V1(r) => &r.benchmark_price,
+            ReportData::V2(r) => &r.benchmark_price,
+            ReportData::V3(r) => &r.benchmark_price,
+            ReportData::V4(r) => &r.price,
+            ReportData::V5(r) => &r.rate,
+            ReportData::V6(r) => &r.price,
+            ReportData::V7(r) => &r.exchange_rate,
+            ReportData::V8(r) => &r.mid_price,
+            ReportData::V9(r) => &r.nav_per_share,
+            ReportData::V10(r) => &r.price,
+            ReportData::V11(r) => &r.mid,
+            ReportData::V12(r) => &r.nav_per_share,
+            ReportData::V13(r) => &r.last_traded_price,
+        }
+    }
+
+    /// Returns the number of decimal places prices are carried to, for schemas that fix it.
+    ///
+    /// Returns `None` for schemas where it depends on the feed (e.g. v3 is "8 or 18 decimals").
+    pub fn decimals(&self) -> Option<u32> {
+        match self {
+            ReportData::V2(_) => Some(ReportDataV2::DECIMALS),
+            ReportData::V8(_) => Some(ReportDataV8::DECIMALS),
+            ReportData::V9(_) => Some(ReportDataV9::DECIMALS),
+            ReportData::V10(_) => Some(ReportDataV10::DECIMALS),
+            ReportData::V11(_) => Some(ReportDataV11::DECIMALS),
+            ReportData::V13(_) => Some(ReportDataV13::DECIMALS),
+            _ => None,
+        }
+    }
+
+    /// Renders the primary price as a decimal string.
+    ///
+    /// Schemas with fixed decimals use them automatically; `decimals` overrides them and is only
+    /// required for schemas where decimals depend on the feed. Returns `None` if the decimals
+    /// are neither fixed by the schema nor provided.
+    pub fn price_to_decimal(&self, decimals: Option<u32>) -> Option<String> {
+        let decimals = decimals.or(self.decimals())?;
+
+        Some(decimal::to_decimal(self.price(), decimals))
+    }
+
+    /// Returns the feed ID the report has data for.
+    pub fn feed_id(&self) -> ID {
+        every_schema!(self, |r| r.feed_id)
+    }
+
+    /// Returns the latest timestamp for which the report's data is applicable, in seconds.
+    pub fn observations_timestamp(&self) -> u32 {
+        every_schema!(self, |r| r.observations_timestamp)
+    }
+
+    /// Returns the earliest timestamp for which the report's data is applicable, in seconds.
+    ///
+    /// Returns `None` for v1 reports, whose validity starts at a block (`valid_from_block_num`).
+    pub fn valid_from_timestamp(&self) -> Option<u32> {
+        v2_onwards!(self, |r| r.valid_from_timestamp)
+    }
+
+    /// Returns the verification fee in the chain's native token.
+    ///
+    /// Returns `None` for v1 reports, which carry no fees.
+    pub fn native_fee(&self) -> Option<&BigInt> {
+        v2_onwards!(self, |r| &r.native_fee)
+    }
+
+    /// Returns the verification fee in LINK.
+    ///
+    /// Returns `None` for v1 reports, which carry no fees.
+    pub fn link_fee(&self) -> Option<&BigInt> {
+        v2_onwards!(self, |r| &r.link_fee)
+    }
+
+    /// Returns the latest timestamp at which the report can be verified onchain, in seconds.
+    ///
+    /// Returns `None` for v1 reports, which do not expire.
+    pub fn expires_at(&self) -> Option<u32> {
+        v2_onwards!(self, |r| r.expires_at)
+    }
+
+    /// Returns the fields shared by all schemas from v2 onwards.
+    ///
+    /// Returns `None` for v1 reports, which predate validity timestamps, fees and expiry.
+    pub fn common_view(&self) -> Option<ReportCommon> {
+        v2_onwards!(self, |r| ReportCommon {
+            feed_id: r.feed_id,
+            valid_from_timestamp: r.valid_from_timestamp,
+            observations_timestamp: r.observations_timestamp,
+            native_fee: r.native_fee.clone(),
+            link_fee: r.link_fee.clone(),
+            expires_at: r.expires_at,
+        })
+    }
+}
+
+/// Renders a one-line summary for logs, e.g.
+/// `v3 feed=0x0003… observed=1731083125 price=3.5 bid=3.49 ask=3.51 expires=1731169525`.
+///
+/// Prices use the schema's fixed decimals. For schemas where decimals depend on the feed, pass
+/// them as the formatter precision, e.g. `format!("{:.18}", report_data)`; without a precision
+/// those prices are rendered as raw integers. A precision also overrides fixed decimals.
+impl fmt::Display for ReportData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = f
+            .precision()
+            .map(|precision| precision as u32)
+            .or(self.decimals());
+        let render = |value: &BigInt| match decimals {
+            Some(decimals) => decimal::to_decimal(value, decimals),
+            None => value.to_string(),
+        };
+
+        write!(
+            f,
+            "v{} feed={} observed={} price={}",
+            self.version(),
+            self.feed_id(),
+            self.observations_timestamp(),
+            render(self.price())
+        )?;
+
+        let bid_ask = match self {
+            ReportData::V3(r) => Some((&r.bid, &r.ask)),
+            ReportData::V11(r) => Some((&r.bid, &r.ask)),
+            ReportData::V13(r) => Some((&r.best_bid, &r.best_ask)),
+            _ => None,
+        };
+        if let Some((bid, ask)) = bid_ask {
+            write!(f, " bid={} ask={}", render(bid), render(ask))?;
+        }
+
+        if let Some(expires_at) = self.expires_at() {
+            write!(f, " expires={}", expires_at)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Fields shared by every report schema from v2 onwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportCommon {
+    pub feed_id: ID,
+    pub valid_from_timestamp: u32,
+    pub observations_timestamp: u32,
+    pub native_fee: BigInt,
+    pub link_fee: BigInt,
+    pub expires_at: u32,
+}
+
+/// Builds a `Report` from typed report data. See [`Report::builder`].
+pub struct ReportBuilder {
+    report_data: ReportData,
+    report_context: [[u8; 32]; 3],
+}
+
+impl ReportBuilder {
+    /// Sets the `report_context` parameter. Defaults to all zeroes.
+    pub fn with_report_context(mut self, report_context: [[u8; 32]; 3]) -> Self {
+        self.report_context = report_context;
+        self
+    }
+
+    /// Builds the `Report`, filling the feed ID and timestamps from the report data.
+    ///
+    /// The signatures of the encoded full report are left empty, so the report decodes
+    /// but will not verify on-chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReportError` if the report data cannot be ABI-encoded.
+    pub fn build(self) -> Result<Report, ReportError> {
+        let (feed_id, valid_from_timestamp, observations_timestamp) = match &self.report_data {
+            // v1 has no valid-from timestamp, only a valid-from block number
+            ReportData::V1(r) => (
+                r.feed_id,
+                r.observations_timestamp,
+                r.observations_timestamp,
+            ),
+            report_data => {
+                let common = report_data.common_view().expect("v2+ report data");
+                (
+                    common.feed_id,
+                    common.valid_from_timestamp,
+                    common.observations_timestamp,
+                )
+            }
+        };
+
+        let report_blob = self.report_data.abi_encode()?;
+        let payload = encode_full_report(
+            &ReportContext::from_words(self.report_context),
+            &report_blob,
+            &[],
+            &[],
+            [0u8; 32],
+        );
+
+        Ok(Report {
+            feed_id,
+            valid_from_timestamp: valid_from_timestamp as usize,
+            observations_timestamp: observations_timestamp as usize,
+            full_report: format!("0x{}", hex::encode(payload)),
+        })
+    }
+}
+
+/// ABI-encodes a report context, report blob and signatures into a full report payload.
+/// This is the inverse of [`decode_full_report_strict`]. Pass empty signatures for a payload that
+/// decodes, but will not verify on-chain.
+///
+/// Solidity Equivalent:
+/// ```solidity
+/// struct ReportCallback {
+///     bytes32[3] reportContext;
+///     bytes reportBlob;
+///     bytes32[] rawRs;
+///     bytes32[] rawSs;
+///     bytes32 rawVs;
+/// }
+/// ```
+pub fn encode_full_report(
+    report_context: &ReportContext,
+    report_blob: &[u8],
+    raw_rs: &[[u8; 32]],
+    raw_ss: &[[u8; 32]],
+    raw_vs: [u8; 32],
+) -> Vec<u8> {
+    fn word(value: usize) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[24..32].copy_from_slice(&(value as u64).to_be_bytes());
+        word
+    }
+
+    let padding =
+        (ReportBase::WORD_SIZE - report_blob.len() % ReportBase::WORD_SIZE) % ReportBase::WORD_SIZE;
+    let padded_blob_len = report_blob.len() + padding;
+
+    // Head: reportContext (3 words), 3 offsets and rawVs
+    let blob_offset = 7 * ReportBase::WORD_SIZE;
+    let raw_rs_offset = blob_offset + ReportBase::WORD_SIZE + padded_blob_len;
+    let raw_ss_offset = raw_rs_offset + ReportBase::WORD_SIZE * (1 + raw_rs.len());
+    let payload_len = raw_ss_offset + ReportBase::WORD_SIZE * (1 + raw_ss.len());
+
+    let mut payload = Vec::with_capacity(payload_len);
+
+    for context in report_context.to_words() {
+        payload.extend_from_slice(&context);
+    }
+    payload.extend_from_slice(&word(blob_offset));
+    payload.extend_from_slice(&word(raw_rs_offset));
+    payload.extend_from_slice(&word(raw_ss_offset));
+    payload.extend_from_slice(&raw_vs);
+
+    payload.extend_from_slice(&word(report_blob.len()));
+    payload.extend_from_slice(report_blob);
+    payload.resize(blob_offset + ReportBase::WORD_SIZE + padded_blob_len, 0);
+
+    for signatures in [raw_rs, raw_ss] {
+        payload.extend_from_slice(&word(signatures.len()));
+        for signature in signatures {
+            payload.extend_from_slice(signature);
+        }
+    }
+
+    payload
+}
+
 /// ABI-decodes a full report payload into its report context (`bytes32[3]`) and report blob (`bytes`).
 /// The report blob is the actual report data that needs to be decoded further - to version-specific report data.
 ///
@@ -82,7 +665,7 @@ pub struct Report {
 ///
 /// # Errors
 ///
-/// Returns a `String` if the payload is too short, the offset is invalid, or the length is invalid.
+/// Returns a `ReportError` if the payload is too short, the offset is invalid, or the length is invalid.
 pub fn decode_full_report(payload: &[u8]) -> Result<(Vec<[u8; 32]>, Vec<u8>), ReportError> {
     if payload.len() < 128 {
         return Err(ReportError::DataTooShort("Payload is too short"));
@@ -94,35 +677,167 @@ pub fn decode_full_report(payload: &[u8]) -> Result<(Vec<[u8; 32]>, Vec<u8>), Re
         .collect::<Result<Vec<_>, _>>()
         .map_err(|_| ReportError::ParseError("report_context"))?;
 
-    // Decode the offset for the bytes reportBlob data
-    let offset = usize::from_be_bytes(
-        payload[96..128][24..ReportBase::WORD_SIZE] // Offset value is stored as Little Endian
-            .try_into()
-            .map_err(|_| ReportError::ParseError("offset as usize"))?,
-    );
+    // Decode the offset for the bytes reportBlob data. Words are read as u64 rather than usize,
+    // which is only 4 bytes on 32-bit targets such as wasm32.
+    let offset = usize::try_from(ReportBase::read_uint64(payload, 96)?)
+        .map_err(|_| ReportError::InvalidLength("offset"))?;
 
     if offset < 128 || offset >= payload.len() {
         return Err(ReportError::InvalidLength("offset"));
     }
 
+    if offset + ReportBase::WORD_SIZE > payload.len() {
+        return Err(ReportError::InvalidLength("length word out of bounds"));
+    }
+
     // Decode the length of the bytes reportBlob data
-    let length = usize::from_be_bytes(
-        payload[offset..offset + 32][24..ReportBase::WORD_SIZE] // Length value is stored as Little Endian
-            .try_into()
-            .map_err(|_| ReportError::ParseError("length as usize"))?,
-    );
+    let length = usize::try_from(ReportBase::read_uint64(payload, offset)?)
+        .map_err(|_| ReportError::InvalidLength("bytes data"))?;
 
-    if offset + ReportBase::WORD_SIZE + length > payload.len() {
-        return Err(ReportError::InvalidLength("bytes data"));
-    }
+    let blob_end = length
+        .checked_add(offset + ReportBase::WORD_SIZE)
+        .filter(|end| *end <= payload.len())
+        .ok_or(ReportError::InvalidLength("bytes data"))?;
 
     // Decode the remainder of the payload (actual bytes reportBlob data)
-    let report_blob =
-        payload[offset + ReportBase::WORD_SIZE..offset + ReportBase::WORD_SIZE + length].to_vec();
+    let report_blob = payload[offset + ReportBase::WORD_SIZE..blob_end].to_vec();
 
     Ok((report_context, report_blob))
 }
 
+/// The `bytes32[3]` report context preceding the report blob in a full report payload.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReportContext {
+    /// Digest of the DON configuration the report was produced under.
+    pub config_digest: [u8; 32],
+    /// OCR epoch and round in which the report was produced.
+    pub epoch_and_round: [u8; 32],
+    /// Extra hash, reserved by the protocol.
+    pub extra_hash: [u8; 32],
+}
+
+impl ReportContext {
+    /// Builds a report context from its three words, in payload order.
+    pub fn from_words(words: [[u8; 32]; 3]) -> Self {
+        let [config_digest, epoch_and_round, extra_hash] = words;
+        ReportContext {
+            config_digest,
+            epoch_and_round,
+            extra_hash,
+        }
+    }
+
+    /// Returns the report context's three words, in payload order.
+    pub fn to_words(&self) -> [[u8; 32]; 3] {
+        [self.config_digest, self.epoch_and_round, self.extra_hash]
+    }
+}
+
+/// ABI-decodes a full report payload like [`decode_full_report`], returning the report context
+/// as a [`ReportContext`] rather than a vector of words.
+///
+/// # Errors
+///
+/// Returns a `ReportError` if the payload is too short, the offset is invalid, or the length is invalid.
+pub fn decode_full_report_typed(payload: &[u8]) -> Result<(ReportContext, Vec<u8>), ReportError> {
+    let (report_context, report_blob) = decode_full_report(payload)?;
+
+    let words: [[u8; 32]; 3] = report_context
+        .try_into()
+        .map_err(|_| ReportError::ParseError("report_context"))?;
+
+    Ok((ReportContext::from_words(words), report_blob))
+}
+
+/// A full report payload with its signatures, as decoded by [`decode_full_report_strict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FullReport {
+    pub report_context: ReportContext,
+    pub report_blob: Vec<u8>,
+    pub raw_rs: Vec<[u8; 32]>,
+    pub raw_ss: Vec<[u8; 32]>,
+    pub raw_vs: [u8; 32],
+}
+
+/// ABI-decodes a full report payload like [`decode_full_report`], additionally parsing the
+/// `rawRs`, `rawSs` and `rawVs` signatures, so they can be verified.
+///
+/// Unlike `decode_full_report`, the payload must be well-formed as a whole: every offset and
+/// declared length must lie within the payload, and no bytes may follow the last dynamic field.
+///
+/// # Errors
+///
+/// Returns a `ReportError` if the payload is truncated, an offset or length points past its end,
+/// or it carries trailing bytes.
+pub fn decode_full_report_strict(payload: &[u8]) -> Result<FullReport, ReportError> {
+    const HEAD_WORDS: usize = 7;
+    let word = ReportBase::WORD_SIZE;
+
+    if payload.len() < HEAD_WORDS * word {
+        return Err(ReportError::DataTooShort("full report head"));
+    }
+
+    let read_word = |offset: usize| -> [u8; 32] {
+        payload[offset..offset + word]
+            .try_into()
+            .expect("word is in bounds")
+    };
+    let read_usize = |offset: usize, field: &'static str| -> Result<usize, ReportError> {
+        if payload[offset..offset + word - 8].iter().any(|b| *b != 0) {
+            return Err(ReportError::InvalidLength(field));
+        }
+        usize::try_from(ReportBase::read_uint64(payload, offset)?)
+            .map_err(|_| ReportError::InvalidLength(field))
+    };
+    // Returns the offset and length of the dynamic field whose offset is stored at `head_offset`
+    let read_dynamic = |head_offset: usize,
+                        element_size: usize,
+                        field: &'static str|
+     -> Result<(usize, usize), ReportError> {
+        let offset = read_usize(head_offset, field)?;
+        if offset < HEAD_WORDS * word || offset.saturating_add(word) > payload.len() {
+            return Err(ReportError::InvalidLength(field));
+        }
+        let length = read_usize(offset, field)?;
+        let end = length
+            .checked_mul(element_size)
+            .and_then(|size| size.checked_add(offset + word))
+            .ok_or(ReportError::InvalidLength(field))?;
+        if end > payload.len() {
+            return Err(ReportError::InvalidLength(field));
+        }
+        Ok((offset + word, length))
+    };
+    let read_words = |start: usize, count: usize| -> Vec<[u8; 32]> {
+        (0..count).map(|i| read_word(start + i * word)).collect()
+    };
+
+    let report_context =
+        ReportContext::from_words([read_word(0), read_word(word), read_word(2 * word)]);
+    let raw_vs = read_word(6 * word);
+
+    let (blob_start, blob_length) = read_dynamic(3 * word, 1, "reportBlob")?;
+    let (rs_start, rs_count) = read_dynamic(4 * word, word, "rawRs")?;
+    let (ss_start, ss_count) = read_dynamic(5 * word, word, "rawSs")?;
+
+    // The blob is padded to a whole number of words
+    let blob_end = blob_start + blob_length + (word - blob_length % word) % word;
+    let end = blob_end
+        .max(rs_start + rs_count * word)
+        .max(ss_start + ss_count * word);
+    if end != payload.len() {
+        return Err(ReportError::InvalidLength("full report trailing data"));
+    }
+
+    Ok(FullReport {
+        report_context,
+        report_blob: payload[blob_start..blob_start + blob_length].to_vec(),
+        raw_rs: read_words(rs_start, rs_count),
+        raw_ss: read_words(ss_start, ss_count),
+        raw_vs,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,51 +853,51 @@ mod tests {
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V2_FEED_ID: ID = ID([
-        00, 02, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 2, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V3_FEED_ID: ID = ID([
-        00, 03, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 3, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V4_FEED_ID: ID = ID([
-        00, 04, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 4, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V5_FEED_ID: ID = ID([
-        00, 05, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 5, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V6_FEED_ID: ID = ID([
-        00, 06, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 6, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V7_FEED_ID: ID = ID([
-        00, 07, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 7, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V8_FEED_ID: ID = ID([
-        00, 08, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 8, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V9_FEED_ID: ID = ID([
-        00, 09, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 9, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V10_FEED_ID: ID = ID([
-        00, 10, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 10, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V11_FEED_ID: ID = ID([
-        00, 11, 251, 109, 19, 88, 151, 228, 170, 245, 101, 123, 255, 211, 176, 180, 143, 142, 42,
+        0, 11, 251, 109, 19, 88, 151, 228, 170, 245, 101, 123, 255, 211, 176, 180, 143, 142, 42,
         81, 49, 33, 76, 158, 194, 214, 46, 172, 93, 83, 32, 103,
     ]);
     const V12_FEED_ID: ID = ID([
-        00, 12, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 12, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V13_FEED_ID: ID = ID([
-        00, 13, 19, 169, 185, 197, 227, 122, 9, 159, 55, 78, 146, 195, 121, 20, 175, 92, 38, 143,
+        0, 13, 19, 169, 185, 197, 227, 122, 9, 159, 55, 78, 146, 195, 121, 20, 175, 92, 38, 143,
         58, 138, 151, 33, 241, 114, 81, 53, 191, 180, 203, 184,
     ]);
 
@@ -202,7 +917,7 @@ mod tests {
     pub const MOCK_MARKET_STATUS: u32 = 2;
 
     pub fn generate_mock_report_data_v1() -> ReportDataV1 {
-        let report_data = ReportDataV1 {
+        ReportDataV1 {
             feed_id: V1_FEED_ID,
             observations_timestamp: MOCK_TIMESTAMP,
             benchmark_price: BigInt::from(MOCK_PRICE),
@@ -215,13 +930,11 @@ mod tests {
             ],
             valid_from_block_num: 768986,
             current_block_timestamp: MOCK_TIMESTAMP as u64,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v2() -> ReportDataV2 {
-        let report_data = ReportDataV2 {
+        ReportDataV2 {
             feed_id: V2_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -229,15 +942,13 @@ mod tests {
             link_fee: BigInt::from(MOCK_FEE),
             expires_at: MOCK_TIMESTAMP + 100,
             benchmark_price: BigInt::from(MOCK_PRICE),
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v3() -> ReportDataV3 {
         let delta = BigInt::from(10) * BigInt::from(MOCK_PRICE) / BigInt::from(100); // 10% of mock_price
 
-        let report_data = ReportDataV3 {
+        ReportDataV3 {
             feed_id: V3_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -247,13 +958,11 @@ mod tests {
             benchmark_price: BigInt::from(MOCK_PRICE),
             bid: MOCK_PRICE - delta.clone(),
             ask: MOCK_PRICE + delta,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v4() -> ReportDataV4 {
-        let report_data = ReportDataV4 {
+        ReportDataV4 {
             feed_id: V4_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -262,15 +971,13 @@ mod tests {
             expires_at: MOCK_TIMESTAMP + 100,
             price: BigInt::from(MOCK_PRICE),
             market_status: MARKET_STATUS_OPEN,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v5() -> ReportDataV5 {
         let one_hour_in_seconds: u32 = 3600;
 
-        let report_data = ReportDataV5 {
+        ReportDataV5 {
             feed_id: V5_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -280,13 +987,11 @@ mod tests {
             rate: BigInt::from(MOCK_PRICE),
             timestamp: MOCK_TIMESTAMP,
             duration: one_hour_in_seconds,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v6() -> ReportDataV6 {
-        let report_data = ReportDataV6 {
+        ReportDataV6 {
             feed_id: V6_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -298,13 +1003,11 @@ mod tests {
             price3: BigInt::from(MOCK_PRICE + 20),
             price4: BigInt::from(MOCK_PRICE + 30),
             price5: BigInt::from(MOCK_PRICE + 40),
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v7() -> ReportDataV7 {
-        let report_data = ReportDataV7 {
+        ReportDataV7 {
             feed_id: V7_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -312,13 +1015,11 @@ mod tests {
             link_fee: BigInt::from(MOCK_FEE),
             expires_at: MOCK_TIMESTAMP + 100,
             exchange_rate: BigInt::from(MOCK_PRICE),
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v8() -> ReportDataV8 {
-        let report_data = ReportDataV8 {
+        ReportDataV8 {
             feed_id: V8_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -328,9 +1029,7 @@ mod tests {
             last_update_timestamp: MOCK_TIMESTAMP as u64,
             mid_price: BigInt::from(MOCK_PRICE),
             market_status: MARKET_STATUS_OPEN,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v9() -> ReportDataV9 {
@@ -338,7 +1037,7 @@ mod tests {
         const MOCK_AUM: isize = 1000;
         const RIPCORD_NORMAL: u32 = 0;
 
-        let report_data = ReportDataV9 {
+        ReportDataV9 {
             feed_id: V9_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -349,15 +1048,13 @@ mod tests {
             nav_date: MOCK_TIMESTAMP as u64,
             aum: BigInt::from(MOCK_AUM),
             ripcord: RIPCORD_NORMAL,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v10() -> ReportDataV10 {
         const MOCK_MULTIPLIER: isize = 1000000000000000000; // 1.0 with 18 decimals
 
-        let report_data = ReportDataV10 {
+        ReportDataV10 {
             feed_id: V10_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -371,15 +1068,13 @@ mod tests {
             new_multiplier: BigInt::from(MOCK_MULTIPLIER),
             activation_date_time: MOCK_TIMESTAMP + 200,
             tokenized_price: BigInt::from(MOCK_PRICE * 2),
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v11() -> ReportDataV11 {
         let multiplier: BigInt = "1000000000000000000".parse::<BigInt>().unwrap(); // 1.0 with 18 decimals
 
-        let report_data = ReportDataV11 {
+        ReportDataV11 {
             feed_id: V11_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -396,9 +1091,7 @@ mod tests {
                 .checked_mul(&multiplier)
                 .unwrap(),
             market_status: MOCK_MARKET_STATUS,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v12() -> ReportDataV12 {
@@ -406,7 +1099,7 @@ mod tests {
         const MOCK_NEXT_NAV_PER_SHARE: isize = 2;
         const RIPCORD_NORMAL: u32 = 0;
 
-        let report_data = ReportDataV12 {
+        ReportDataV12 {
             feed_id: V12_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -417,15 +1110,13 @@ mod tests {
             next_nav_per_share: BigInt::from(MOCK_NEXT_NAV_PER_SHARE),
             nav_date: MOCK_TIMESTAMP as i64,
             ripcord: RIPCORD_NORMAL,
-        };
-
-        report_data
+        }
     }
 
     pub fn generate_mock_report_data_v13() -> ReportDataV13 {
         let multiplier: BigInt = "1000000000000000000".parse::<BigInt>().unwrap(); // 1.0 with 18 decimals
 
-        let report_data = ReportDataV13 {
+        ReportDataV13 {
             feed_id: V13_FEED_ID,
             valid_from_timestamp: MOCK_TIMESTAMP,
             observations_timestamp: MOCK_TIMESTAMP,
@@ -443,9 +1134,7 @@ mod tests {
             last_traded_price: BigInt::from(MOCK_LAST_TRADED_PRICE)
                 .checked_mul(&multiplier)
                 .unwrap(),
-        };
-
-        report_data
+        }
     }
 
     fn generate_mock_report(encoded_report_data: &[u8]) -> Vec<u8> {
@@ -474,7 +1163,7 @@ mod tests {
     }
 
     fn bytes(hex_str: &str) -> Vec<u8> {
-        if hex_str.len() % 2 != 0 {
+        if !hex_str.len().is_multiple_of(2) {
             panic!("Invalid hex string: odd number of characters");
         }
 
@@ -486,6 +1175,226 @@ mod tests {
             .collect()
     }
 
+    /// A full report payload signed by two oracles.
+    fn generate_mock_signed_report(report_blob: &[u8]) -> Vec<u8> {
+        let word = |value: usize| {
+            let mut word = [0u8; 32];
+            word[24..32].copy_from_slice(&(value as u64).to_be_bytes());
+            word
+        };
+
+        let padded_blob_len = report_blob.len() + (32 - report_blob.len() % 32) % 32;
+        let blob_offset = 7 * 32;
+        let raw_rs_offset = blob_offset + 32 + padded_blob_len;
+        let raw_ss_offset = raw_rs_offset + 3 * 32;
+
+        let mut payload = Vec::new();
+        for context in [[1u8; 32], [2u8; 32], [3u8; 32]] {
+            payload.extend_from_slice(&context);
+        }
+        payload.extend_from_slice(&word(blob_offset));
+        payload.extend_from_slice(&word(raw_rs_offset));
+        payload.extend_from_slice(&word(raw_ss_offset));
+        payload.extend_from_slice(&[9u8; 32]); // rawVs
+
+        payload.extend_from_slice(&word(report_blob.len()));
+        payload.extend_from_slice(report_blob);
+        payload.resize(blob_offset + 32 + padded_blob_len, 0);
+
+        payload.extend_from_slice(&word(2));
+        payload.extend_from_slice(&[0xa1; 32]);
+        payload.extend_from_slice(&[0xa2; 32]);
+        payload.extend_from_slice(&word(2));
+        payload.extend_from_slice(&[0xb1; 32]);
+        payload.extend_from_slice(&[0xb2; 32]);
+
+        payload
+    }
+
+    #[test]
+    fn test_decode_full_report_strict() {
+        let report_blob = generate_mock_report_data_v3().abi_encode().unwrap();
+        let payload = generate_mock_signed_report(&report_blob);
+
+        let full_report = decode_full_report_strict(&payload).unwrap();
+        assert_eq!(full_report.report_context.config_digest, [1u8; 32]);
+        assert_eq!(full_report.report_context.epoch_and_round, [2u8; 32]);
+        assert_eq!(full_report.report_context.extra_hash, [3u8; 32]);
+        assert_eq!(full_report.report_blob, report_blob);
+        assert_eq!(full_report.raw_rs, vec![[0xa1; 32], [0xa2; 32]]);
+        assert_eq!(full_report.raw_ss, vec![[0xb1; 32], [0xb2; 32]]);
+        assert_eq!(full_report.raw_vs, [9u8; 32]);
+
+        // Agrees with the lenient decoder
+        let (report_context, blob) = decode_full_report(&payload).unwrap();
+        assert_eq!(
+            report_context,
+            full_report.report_context.to_words().to_vec()
+        );
+        assert_eq!(blob, full_report.report_blob);
+
+        // Unsigned payloads, as written by `encode_full_report`, decode too
+        let unsigned =
+            encode_full_report(&ReportContext::default(), &report_blob, &[], &[], [0u8; 32]);
+        let full_report = decode_full_report_strict(&unsigned).unwrap();
+        assert_eq!(full_report.report_blob, report_blob);
+        assert!(full_report.raw_rs.is_empty());
+        assert!(full_report.raw_ss.is_empty());
+    }
+
+    #[test]
+    fn test_encode_full_report_round_trip() {
+        let report_blob = generate_mock_report_data_v3().abi_encode().unwrap();
+        let full_report = FullReport {
+            report_context: ReportContext::from_words([[1u8; 32], [2u8; 32], [3u8; 32]]),
+            report_blob: report_blob.clone(),
+            raw_rs: vec![[0xa1; 32], [0xa2; 32]],
+            raw_ss: vec![[0xb1; 32], [0xb2; 32]],
+            raw_vs: [9u8; 32],
+        };
+
+        let payload = encode_full_report(
+            &full_report.report_context,
+            &full_report.report_blob,
+            &full_report.raw_rs,
+            &full_report.raw_ss,
+            full_report.raw_vs,
+        );
+
+        assert_eq!(payload, generate_mock_signed_report(&report_blob));
+        assert_eq!(decode_full_report_strict(&payload).unwrap(), full_report);
+    }
+
+    #[test]
+    fn test_decode_full_report_fuzz() {
+        // xorshift64, so failures reproduce from the fixed seed
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let report_blob = generate_mock_report_data_v3().abi_encode().unwrap();
+        let payload = generate_mock_signed_report(&report_blob);
+
+        for _ in 0..20_000 {
+            let mut input = if next() % 2 == 0 {
+                let len = (next() % 512) as usize;
+                (0..len).map(|_| next() as u8).collect::<Vec<_>>()
+            } else {
+                payload.clone()
+            };
+
+            // Point a head or length word somewhere near the end of the input
+            if input.len() >= 32 && next() % 4 != 0 {
+                let word = (next() as usize % (input.len() / 32)) * 32;
+                let value = match next() % 3 {
+                    0 => (input.len() as u64).wrapping_sub(next() % 64),
+                    1 => u64::MAX - next() % 64,
+                    _ => next(),
+                };
+                input[word..word + 24].fill(0);
+                input[word + 24..word + 32].copy_from_slice(&value.to_be_bytes());
+            }
+            input.truncate((next() as usize % (input.len() + 1)).max(input.len() / 2));
+
+            if let Ok((_, blob)) = decode_full_report(&input) {
+                assert!(blob.len() <= input.len());
+            }
+            let _ = decode_full_report_typed(&input);
+            let _ = decode_full_report_strict(&input);
+        }
+    }
+
+    #[test]
+    fn test_decode_full_report_offset_near_end() {
+        // Head of 4 words whose offset points 10 bytes before the end, inside the payload but
+        // with no room for the length word
+        let mut payload = vec![0u8; 160];
+        let offset = payload.len() - 10;
+        payload[120..128].copy_from_slice(&(offset as u64).to_be_bytes());
+
+        assert_eq!(
+            decode_full_report(&payload),
+            Err(ReportError::InvalidLength("length word out of bounds"))
+        );
+    }
+
+    #[test]
+    fn test_decode_full_report_words_beyond_u32() {
+        // Offset and length words above u32::MAX must be rejected the same way on 32-bit targets
+        let mut payload = vec![0u8; 160];
+        payload[120..128].copy_from_slice(&(u32::MAX as u64 + 128).to_be_bytes());
+        assert_eq!(
+            decode_full_report(&payload),
+            Err(ReportError::InvalidLength("offset"))
+        );
+
+        payload[120..128].copy_from_slice(&128u64.to_be_bytes());
+        payload[152..160].copy_from_slice(&(u32::MAX as u64 + 1).to_be_bytes());
+        assert_eq!(
+            decode_full_report(&payload),
+            Err(ReportError::InvalidLength("bytes data"))
+        );
+    }
+
+    #[test]
+    fn test_decode_full_report_typed() {
+        let report_blob = generate_mock_report_data_v3().abi_encode().unwrap();
+        let payload = generate_mock_signed_report(&report_blob);
+
+        let (report_context, blob) = decode_full_report_typed(&payload).unwrap();
+        assert_eq!(report_context.config_digest, [1u8; 32]);
+        assert_eq!(report_context.epoch_and_round, [2u8; 32]);
+        assert_eq!(report_context.extra_hash, [3u8; 32]);
+        assert_eq!(blob, report_blob);
+
+        let (words, _) = decode_full_report(&payload).unwrap();
+        assert_eq!(report_context.to_words().to_vec(), words);
+        assert_eq!(
+            ReportContext::from_words(report_context.to_words()),
+            report_context
+        );
+    }
+
+    #[test]
+    fn test_decode_full_report_strict_rejects_malformed_payloads() {
+        let report_blob = generate_mock_report_data_v3().abi_encode().unwrap();
+        let payload = generate_mock_signed_report(&report_blob);
+
+        let mut trailing = payload.clone();
+        trailing.push(0);
+        assert!(decode_full_report(&trailing).is_ok());
+        assert!(matches!(
+            decode_full_report_strict(&trailing),
+            Err(ReportError::InvalidLength(_))
+        ));
+
+        // Declares more rawSs entries than the payload holds
+        let mut overlong = payload.clone();
+        let raw_ss_length = overlong.len() - 3 * 32;
+        overlong[raw_ss_length + 31] = 3;
+        assert!(matches!(
+            decode_full_report_strict(&overlong),
+            Err(ReportError::InvalidLength("rawSs"))
+        ));
+
+        // rawRs offset points past the payload
+        let mut bad_offset = payload.clone();
+        bad_offset[4 * 32 + 24..5 * 32].copy_from_slice(&u64::MAX.to_be_bytes());
+        assert!(matches!(
+            decode_full_report_strict(&bad_offset),
+            Err(ReportError::InvalidLength("rawRs"))
+        ));
+
+        assert!(matches!(
+            decode_full_report_strict(&payload[..6 * 32]),
+            Err(ReportError::DataTooShort(_))
+        ));
+    }
+
     #[test]
     fn test_decode_report_v1() {
         let report_data = generate_mock_report_data_v1();
@@ -526,7 +1435,7 @@ mod tests {
 
         let (_report_context, report_blob) = decode_full_report(&report).unwrap();
 
-        let expected_report_blob = vec![
+        let expected_report_blob = [
             "00026b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472",
             "0000000000000000000000000000000000000000000000000000000066741d8c",
             "0000000000000000000000000000000000000000000000000000000066741d8c",
@@ -586,7 +1495,7 @@ mod tests {
 
         let (_report_context, report_blob) = decode_full_report(&report).unwrap();
 
-        let expected_report_blob = vec![
+        let expected_report_blob = [
             "00046b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472",
             "0000000000000000000000000000000000000000000000000000000066741d8c",
             "0000000000000000000000000000000000000000000000000000000066741d8c",
@@ -680,7 +1589,7 @@ mod tests {
 
         let (_report_context, report_blob) = decode_full_report(&report).unwrap();
 
-        let expected_report_blob = vec![
+        let expected_report_blob = [
             "00076b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472",
             "0000000000000000000000000000000000000000000000000000000066741d8c",
             "0000000000000000000000000000000000000000000000000000000066741d8c",
@@ -898,4 +1807,389 @@ mod tests {
 
         assert_eq!(decoded_report.feed_id, V13_FEED_ID);
     }
+
+    #[test]
+    fn test_report_decode_typed() {
+        let report_data = generate_mock_report_data_v3();
+        let encoded_report_data = report_data.abi_encode().unwrap();
+
+        let report = Report {
+            feed_id: V3_FEED_ID,
+            valid_from_timestamp: MOCK_TIMESTAMP as usize,
+            observations_timestamp: MOCK_TIMESTAMP as usize,
+            full_report: format!(
+                "0x{}",
+                hex::encode(generate_mock_report(&encoded_report_data))
+            ),
+        };
+
+        match report.decode_typed().unwrap() {
+            ReportData::V3(decoded_report) => assert_eq!(decoded_report.feed_id, V3_FEED_ID),
+            other => panic!("Expected ReportData::V3, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_report_decode_typed_unsupported_version() {
+        let report_data = generate_mock_report_data_v3();
+        let encoded_report_data = report_data.abi_encode().unwrap();
+
+        let mut feed_id = V3_FEED_ID;
+        feed_id.0[1] = 0xff;
+
+        let report = Report {
+            feed_id,
+            valid_from_timestamp: MOCK_TIMESTAMP as usize,
+            observations_timestamp: MOCK_TIMESTAMP as usize,
+            full_report: hex::encode(generate_mock_report(&encoded_report_data)),
+        };
+
+        match report.decode_typed() {
+            Err(ReportError::UnsupportedVersion { version, feed_id }) => {
+                assert_eq!(version, 0xff);
+                assert_eq!(feed_id, report.feed_id.to_hex_string());
+            }
+            other => panic!("Expected UnsupportedVersion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_for_dispatches_by_feed_version() {
+        let v3 = ReportData::decode_for(
+            V3_FEED_ID,
+            &generate_mock_report_data_v3().abi_encode().unwrap(),
+        )
+        .unwrap();
+        let v13 = ReportData::decode_for(
+            V13_FEED_ID,
+            &generate_mock_report_data_v13().abi_encode().unwrap(),
+        )
+        .unwrap();
+
+        assert!(matches!(v3, ReportData::V3(_)));
+        assert_eq!(v3.version(), 3);
+        assert!(matches!(v13, ReportData::V13(_)));
+        assert_eq!(v13.version(), 13);
+    }
+
+    #[test]
+    fn test_decode_for_unsupported_version() {
+        let mut feed_id = V3_FEED_ID;
+        feed_id.0[0] = 0x01;
+
+        let blob = generate_mock_report_data_v3().abi_encode().unwrap();
+
+        match ReportData::decode_for(feed_id, &blob) {
+            Err(ReportError::UnsupportedVersion {
+                version,
+                feed_id: reported,
+            }) => {
+                assert_eq!(version, 0x0103);
+                assert_eq!(reported, feed_id.to_hex_string());
+            }
+            other => panic!("Expected UnsupportedVersion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_report_decode_typed_invalid_hex() {
+        let report = Report {
+            feed_id: V3_FEED_ID,
+            valid_from_timestamp: MOCK_TIMESTAMP as usize,
+            observations_timestamp: MOCK_TIMESTAMP as usize,
+            full_report: "0xnothex".to_string(),
+        };
+
+        assert!(matches!(
+            report.decode_typed(),
+            Err(ReportError::ParseError("full_report"))
+        ));
+    }
+
+    #[test]
+    fn test_full_report_bytes() {
+        let report = Report::builder(ReportData::V3(generate_mock_report_data_v3()))
+            .build()
+            .unwrap();
+        let payload = hex::decode(&report.full_report[2..]).unwrap();
+
+        assert_eq!(report.full_report_bytes().unwrap(), payload);
+
+        let unprefixed = Report {
+            full_report: report.full_report[2..].to_string(),
+            ..report.clone()
+        };
+        assert_eq!(unprefixed.full_report_bytes().unwrap(), payload);
+
+        let invalid = Report {
+            full_report: "0xnothex".to_string(),
+            ..report
+        };
+        assert!(invalid.full_report_bytes().is_err());
+    }
+
+    #[test]
+    fn test_report_data_display() {
+        let v3 = ReportData::V3(generate_mock_report_data_v3());
+        let feed_id = v3.feed_id();
+
+        // v3 decimals depend on the feed, so prices are raw unless a precision is given
+        assert_eq!(
+            v3.to_string(),
+            format!(
+                "v3 feed={} observed=1718885772 price=100 bid=90 ask=110 expires=1718885872",
+                feed_id
+            )
+        );
+        assert_eq!(
+            format!("{:.2}", v3),
+            format!(
+                "v3 feed={} observed=1718885772 price=1.00 bid=0.90 ask=1.10 expires=1718885872",
+                feed_id
+            )
+        );
+
+        let v2 = ReportData::V2(generate_mock_report_data_v2());
+        assert_eq!(
+            v2.to_string(),
+            format!(
+                "v2 feed={} observed=1718885772 price=0.00000100 expires=1718885872",
+                v2.feed_id()
+            )
+        );
+
+        let v1 = ReportData::V1(generate_mock_report_data_v1());
+        assert_eq!(
+            v1.to_string(),
+            format!("v1 feed={} observed=1718885772 price=100", v1.feed_id())
+        );
+    }
+
+    #[test]
+    fn test_decode_reports() {
+        let v3 = Report::builder(ReportData::V3(generate_mock_report_data_v3()))
+            .build()
+            .unwrap();
+        let v13 = Report::builder(ReportData::V13(generate_mock_report_data_v13()))
+            .build()
+            .unwrap();
+        let invalid = Report {
+            full_report: "0xnothex".to_string(),
+            ..v3.clone()
+        };
+
+        let decoded = decode_reports(&[v3, invalid, v13]);
+
+        assert_eq!(decoded.len(), 3);
+        assert!(matches!(decoded[0], Ok(ReportData::V3(_))));
+        assert!(matches!(
+            decoded[1],
+            Err(ReportError::ParseError("full_report"))
+        ));
+        assert!(matches!(decoded[2], Ok(ReportData::V13(_))));
+        assert!(decode_reports(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_decode_as_falls_back_to_base_schema() {
+        let report_data = generate_mock_report_data_v8();
+        let encoded_report_data = report_data.abi_encode().unwrap();
+
+        assert!(matches!(
+            ReportData::decode(10, &encoded_report_data),
+            Err(ReportError::DataTooShort(_))
+        ));
+
+        match ReportData::decode_as(10, &encoded_report_data).unwrap() {
+            ReportData::V8(decoded_report) => assert_eq!(decoded_report.feed_id, V8_FEED_ID),
+            other => panic!("Expected ReportData::V8, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_as_ignores_extended_fields() {
+        let report_data = generate_mock_report_data_v10();
+        let encoded_report_data = report_data.abi_encode().unwrap();
+
+        match ReportData::decode_as(8, &encoded_report_data).unwrap() {
+            ReportData::V8(decoded_report) => {
+                assert_eq!(decoded_report.feed_id, report_data.feed_id);
+                assert_eq!(
+                    decoded_report.last_update_timestamp,
+                    report_data.last_update_timestamp
+                );
+                assert_eq!(decoded_report.mid_price, report_data.price);
+                assert_eq!(decoded_report.market_status, report_data.market_status);
+            }
+            other => panic!("Expected ReportData::V8, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_as_without_base_schema() {
+        let report_data = generate_mock_report_data_v8();
+        let encoded_report_data = report_data.abi_encode().unwrap();
+
+        assert!(matches!(
+            ReportData::decode_as(13, &encoded_report_data[..8 * ReportBase::WORD_SIZE]),
+            Err(ReportError::DataTooShort(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_as_unsupported_version() {
+        let report_data = generate_mock_report_data_v3();
+        let encoded_report_data = report_data.abi_encode().unwrap();
+
+        match ReportData::decode_as(0xff, &encoded_report_data) {
+            Err(ReportError::UnsupportedVersion { version, feed_id }) => {
+                assert_eq!(version, 0xff);
+                assert_eq!(feed_id, V3_FEED_ID.to_hex_string());
+            }
+            other => panic!("Expected UnsupportedVersion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_common_view() {
+        let v9 = generate_mock_report_data_v9();
+        let v12 = generate_mock_report_data_v12();
+
+        let common_v9 = ReportData::decode(9, &v9.abi_encode().unwrap())
+            .unwrap()
+            .common_view()
+            .unwrap();
+        let common_v12 = ReportData::decode(12, &v12.abi_encode().unwrap())
+            .unwrap()
+            .common_view()
+            .unwrap();
+
+        assert_eq!(common_v9.feed_id, V9_FEED_ID);
+        assert_eq!(common_v12.feed_id, V12_FEED_ID);
+
+        for common in [common_v9, common_v12] {
+            assert_eq!(common.valid_from_timestamp, MOCK_TIMESTAMP);
+            assert_eq!(common.observations_timestamp, MOCK_TIMESTAMP);
+            assert_eq!(common.native_fee, BigInt::from(MOCK_FEE));
+            assert_eq!(common.link_fee, BigInt::from(MOCK_FEE));
+            assert_eq!(common.expires_at, MOCK_TIMESTAMP + 100);
+        }
+
+        let v1 = generate_mock_report_data_v1();
+        let decoded_v1 = ReportData::decode(1, &v1.abi_encode().unwrap()).unwrap();
+        assert!(decoded_v1.common_view().is_none());
+    }
+
+    #[test]
+    fn test_v1_accessors() {
+        let v1 = generate_mock_report_data_v1();
+        let report = generate_mock_report(&v1.abi_encode().unwrap());
+        let (_, report_blob) = decode_full_report(&report).unwrap();
+
+        let decoded = ReportData::decode_as(1, &report_blob).unwrap();
+        assert!(matches!(decoded, ReportData::V1(_)));
+
+        // Defined for every schema
+        assert_eq!(decoded.feed_id(), V1_FEED_ID);
+        assert_eq!(decoded.observations_timestamp(), MOCK_TIMESTAMP);
+        assert_eq!(decoded.price(), &BigInt::from(MOCK_PRICE));
+
+        // Absent from the block-based v1 schema
+        assert_eq!(decoded.valid_from_timestamp(), None);
+        assert_eq!(decoded.native_fee(), None);
+        assert_eq!(decoded.link_fee(), None);
+        assert_eq!(decoded.expires_at(), None);
+        assert!(decoded.common_view().is_none());
+        assert_eq!(decoded.decimals(), None);
+    }
+
+    #[test]
+    fn test_accessors_from_v2_onwards() {
+        let v3 = generate_mock_report_data_v3();
+        let decoded = ReportData::decode_as(3, &v3.abi_encode().unwrap()).unwrap();
+
+        assert_eq!(decoded.feed_id(), V3_FEED_ID);
+        assert_eq!(decoded.observations_timestamp(), MOCK_TIMESTAMP);
+        assert_eq!(decoded.valid_from_timestamp(), Some(MOCK_TIMESTAMP));
+        assert_eq!(decoded.native_fee(), Some(&BigInt::from(MOCK_FEE)));
+        assert_eq!(decoded.link_fee(), Some(&BigInt::from(MOCK_FEE)));
+        assert_eq!(decoded.expires_at(), Some(MOCK_TIMESTAMP + 100));
+    }
+
+    #[test]
+    fn test_report_builder() {
+        let report_data = generate_mock_report_data_v3();
+        let report_context = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+        let report = Report::builder(ReportData::V3(report_data))
+            .with_report_context(report_context)
+            .build()
+            .unwrap();
+
+        assert_eq!(report.feed_id, V3_FEED_ID);
+        assert_eq!(report.valid_from_timestamp, MOCK_TIMESTAMP as usize);
+        assert_eq!(report.observations_timestamp, MOCK_TIMESTAMP as usize);
+
+        let payload = hex::decode(&report.full_report[2..]).unwrap();
+        let (decoded_context, report_blob) = decode_full_report(&payload).unwrap();
+        assert_eq!(decoded_context, report_context.to_vec());
+        assert_eq!(
+            report_blob,
+            generate_mock_report_data_v3().abi_encode().unwrap()
+        );
+
+        match report.decode_typed().unwrap() {
+            ReportData::V3(decoded_report) => {
+                assert_eq!(decoded_report.benchmark_price, BigInt::from(MOCK_PRICE))
+            }
+            other => panic!("Expected ReportData::V3, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_report_builder_v1() {
+        let report = Report::builder(ReportData::V1(generate_mock_report_data_v1()))
+            .build()
+            .unwrap();
+
+        assert_eq!(report.feed_id, V1_FEED_ID);
+        assert_eq!(report.valid_from_timestamp, MOCK_TIMESTAMP as usize);
+        assert_eq!(report.observations_timestamp, MOCK_TIMESTAMP as usize);
+        assert!(matches!(report.decode_typed().unwrap(), ReportData::V1(_)));
+    }
+
+    #[test]
+    fn test_encode_full_report_pads_blob() {
+        let report_blob = vec![0xab; 33];
+
+        let payload =
+            encode_full_report(&ReportContext::default(), &report_blob, &[], &[], [0u8; 32]);
+
+        assert_eq!(payload.len() % ReportBase::WORD_SIZE, 0);
+        let (_report_context, decoded_blob) = decode_full_report(&payload).unwrap();
+        assert_eq!(decoded_blob, report_blob);
+    }
+
+    #[test]
+    fn test_price_to_decimal_uses_schema_decimals() {
+        let mut report_data = generate_mock_report_data_v2();
+        report_data.benchmark_price = BigInt::from(6_954_102_420_i64);
+        let report_data = ReportData::V2(report_data);
+
+        assert_eq!(report_data.decimals(), Some(8));
+        assert_eq!(report_data.price_to_decimal(None).unwrap(), "69.54102420");
+        assert_eq!(
+            report_data.price_to_decimal(Some(18)).unwrap(),
+            "0.000000006954102420"
+        );
+    }
+
+    #[test]
+    fn test_price_to_decimal_requires_decimals_for_variable_schemas() {
+        let report_data = ReportData::V3(generate_mock_report_data_v3());
+
+        assert_eq!(report_data.decimals(), None);
+        assert_eq!(report_data.price_to_decimal(None), None);
+        assert_eq!(report_data.price_to_decimal(Some(8)).unwrap(), "0.00000100");
+    }
 }
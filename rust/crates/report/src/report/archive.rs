@@ -0,0 +1,241 @@
+use super::compress::{compress_report_raw, decompress_report, CompressionError};
+use super::Report;
+
+use std::io::{self, ErrorKind, Read, Write};
+use thiserror::Error;
+
+/// Upper bound on the size of a single compressed frame, guarding against corrupt length prefixes.
+pub const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Compression error: {0}")]
+    Compression(#[from] CompressionError),
+
+    #[error("Frame of {0} bytes exceeds the maximum frame length")]
+    FrameTooLarge(usize),
+}
+
+/// Appends reports to an archive, one compressed frame per report.
+///
+/// Each frame is a 4-byte big-endian length followed by that many bytes of the report's JSON
+/// encoding, compressed with [`compress_report_raw`]. Frames are self-contained, so an archive
+/// can be reopened and appended to, or several archives concatenated, and still be read back
+/// with [`ReportArchiveReader`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chainlink_data_streams_report::report::archive::{ReportArchiveReader, ReportArchiveWriter};
+/// use chainlink_data_streams_report::report::Report;
+/// use chainlink_data_streams_report::feed_id::ID;
+///
+/// let report = Report {
+///     feed_id: ID::from_hex_str("0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap(),
+///     valid_from_timestamp: 1718885772,
+///     observations_timestamp: 1718885772,
+///     full_report: "0x".to_string(),
+/// };
+///
+/// let mut writer = ReportArchiveWriter::new(Vec::new());
+/// writer.write_report(&report).unwrap();
+/// let archive = writer.into_inner();
+///
+/// let reports = ReportArchiveReader::new(archive.as_slice())
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(reports, vec![report]);
+/// ```
+pub struct ReportArchiveWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> ReportArchiveWriter<W> {
+    /// Creates a writer appending frames to `inner`.
+    ///
+    /// Frames are written with one `write_all` each; wrap `inner` in a `BufWriter` when writing
+    /// to a file or socket.
+    pub fn new(inner: W) -> Self {
+        ReportArchiveWriter { inner }
+    }
+
+    /// Compresses `report` and appends it as a single frame.
+    pub fn write_report(&mut self, report: &Report) -> Result<(), ArchiveError> {
+        let payload = serde_json::to_vec(report).map_err(CompressionError::SerializationError)?;
+        let compressed = compress_report_raw(&payload)?;
+
+        if compressed.len() > MAX_FRAME_LEN {
+            return Err(ArchiveError::FrameTooLarge(compressed.len()));
+        }
+
+        let mut frame = Vec::with_capacity(4 + compressed.len());
+        frame.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&compressed);
+        self.inner.write_all(&frame)?;
+
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<(), ArchiveError> {
+        self.inner.flush()?;
+        Ok(())
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads reports back from an archive written by [`ReportArchiveWriter`], in the order they were
+/// written.
+///
+/// Iteration ends cleanly at the end of the input. An input that ends partway through a frame
+/// yields an `ArchiveError::Io` with `ErrorKind::UnexpectedEof`.
+pub struct ReportArchiveReader<R: Read> {
+    inner: R,
+}
+
+impl<R: Read> ReportArchiveReader<R> {
+    /// Creates a reader over `inner`.
+    pub fn new(inner: R) -> Self {
+        ReportArchiveReader { inner }
+    }
+
+    /// Reads the next report, or returns `Ok(None)` at the end of the archive.
+    pub fn read_report(&mut self) -> Result<Option<Report>, ArchiveError> {
+        let mut len = [0u8; 4];
+        if !self.read_len(&mut len)? {
+            return Ok(None);
+        }
+
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_FRAME_LEN {
+            return Err(ArchiveError::FrameTooLarge(len));
+        }
+
+        let mut compressed = vec![0u8; len];
+        self.inner.read_exact(&mut compressed)?;
+
+        Ok(Some(decompress_report(&compressed)?))
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Fills `len` with the next length prefix. Returns `false` if the input ended before it.
+    fn read_len(&mut self, len: &mut [u8; 4]) -> Result<bool, ArchiveError> {
+        let mut read = 0;
+
+        while read < len.len() {
+            match self.inner.read(&mut len[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => return Err(io::Error::from(ErrorKind::UnexpectedEof).into()),
+                Ok(n) => read += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for ReportArchiveReader<R> {
+    type Item = Result<Report, ArchiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_report().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed_id::ID;
+
+    fn mock_report(i: usize) -> Report {
+        Report {
+            feed_id: ID::from_hex_str(
+                "0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472",
+            )
+            .unwrap(),
+            valid_from_timestamp: 1718885772 + i,
+            observations_timestamp: 1718885772 + i,
+            full_report: format!("0x{:064x}", i),
+        }
+    }
+
+    #[test]
+    fn test_archive_round_trip() {
+        let reports = (0..1000).map(mock_report).collect::<Vec<_>>();
+
+        let mut writer = ReportArchiveWriter::new(Vec::new());
+        for report in &reports {
+            writer.write_report(report).unwrap();
+        }
+        writer.flush().unwrap();
+        let archive = writer.into_inner();
+
+        let read = ReportArchiveReader::new(archive.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(read, reports);
+    }
+
+    #[test]
+    fn test_archive_append() {
+        let mut writer = ReportArchiveWriter::new(Vec::new());
+        writer.write_report(&mock_report(0)).unwrap();
+
+        // Reopening the same sink and appending continues the archive
+        let mut writer = ReportArchiveWriter::new(writer.into_inner());
+        writer.write_report(&mock_report(1)).unwrap();
+        let archive = writer.into_inner();
+
+        let read = ReportArchiveReader::new(archive.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(read, vec![mock_report(0), mock_report(1)]);
+    }
+
+    #[test]
+    fn test_archive_empty() {
+        let mut reader = ReportArchiveReader::new(&[][..]);
+        assert!(reader.read_report().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_archive_truncated() {
+        let mut writer = ReportArchiveWriter::new(Vec::new());
+        writer.write_report(&mock_report(0)).unwrap();
+        let archive = writer.into_inner();
+
+        for truncated in [&archive[..2], &archive[..archive.len() - 1]] {
+            let mut reader = ReportArchiveReader::new(truncated);
+            match reader.read_report() {
+                Err(ArchiveError::Io(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+                other => panic!("Expected UnexpectedEof, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_archive_frame_too_large() {
+        let archive = u32::MAX.to_be_bytes();
+
+        let mut reader = ReportArchiveReader::new(&archive[..]);
+        assert!(matches!(
+            reader.read_report(),
+            Err(ArchiveError::FrameTooLarge(len)) if len == u32::MAX as usize
+        ));
+    }
+}
@@ -1,7 +1,8 @@
+use alloc::string::String;
 use num_bigint::{BigInt, Sign};
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum ReportError {
     #[error("Data is too short for {0}")]
     DataTooShort(&'static str),
@@ -11,9 +12,29 @@ pub enum ReportError {
 
     #[error("Failed to parse {0}")]
     ParseError(&'static str),
+
+    #[error("Invalid value for {0}")]
+    InvalidValue(&'static str),
+
+    #[error("Unsupported report version {version} (feed ID {feed_id})")]
+    UnsupportedVersion { version: u16, feed_id: String },
 }
 
-pub(crate) struct ReportBase;
+impl ReportError {
+    /// Name of the error variant, stable across releases, for logging and metrics labels.
+    pub fn variant(&self) -> &'static str {
+        match self {
+            ReportError::DataTooShort(_) => "DataTooShort",
+            ReportError::InvalidLength(_) => "InvalidLength",
+            ReportError::ParseError(_) => "ParseError",
+            ReportError::InvalidValue(_) => "InvalidValue",
+            ReportError::UnsupportedVersion { .. } => "UnsupportedVersion",
+        }
+    }
+}
+
+/// ABI word readers and encoders shared by the report schemas.
+pub struct ReportBase;
 
 impl ReportBase {
     pub(crate) const WORD_SIZE: usize = 32;
@@ -27,7 +48,12 @@ impl ReportBase {
     }
 
     pub(crate) fn encode_int192(value: &BigInt) -> Result<[u8; 32], ReportError> {
-        let mut buffer = [0u8; 32];
+        // Sign-extend, so negative values read back as negative
+        let mut buffer = if value.sign() == Sign::Minus {
+            [0xffu8; 32]
+        } else {
+            [0u8; 32]
+        };
         let bytes_value = value.to_signed_bytes_be();
         let len = bytes_value.len();
 
@@ -47,6 +73,18 @@ impl ReportBase {
         Ok(BigInt::from_bytes_be(Sign::Plus, &value_bytes[8..32]))
     }
 
+    /// Like `read_uint192`, but rejects a word whose 8 leading padding bytes are not zero
+    /// instead of silently truncating it.
+    pub(crate) fn read_uint192_strict(data: &[u8], offset: usize) -> Result<BigInt, ReportError> {
+        let value = Self::read_uint192(data, offset)?;
+
+        if data[offset..offset + 8].iter().any(|b| *b != 0) {
+            return Err(ReportError::InvalidValue("uint192"));
+        }
+
+        Ok(value)
+    }
+
     pub(crate) fn encode_uint192(value: &BigInt) -> Result<[u8; 32], ReportError> {
         let mut buffer = [0u8; 32];
         let (_, bytes_value) = value.to_bytes_be();
@@ -60,6 +98,58 @@ impl ReportBase {
         Ok(buffer)
     }
 
+    /// Reads a full 32-byte word at `offset` as an unsigned 256-bit integer.
+    pub fn read_uint256(data: &[u8], offset: usize) -> Result<BigInt, ReportError> {
+        if offset + Self::WORD_SIZE > data.len() {
+            return Err(ReportError::DataTooShort("uint256"));
+        }
+        let value_bytes = &data[offset..offset + Self::WORD_SIZE];
+        Ok(BigInt::from_bytes_be(Sign::Plus, value_bytes))
+    }
+
+    /// Encodes a non-negative integer below 2^256 as a 32-byte big-endian word.
+    pub fn encode_uint256(value: &BigInt) -> Result<[u8; 32], ReportError> {
+        let mut buffer = [0u8; 32];
+        let (sign, bytes_value) = value.to_bytes_be();
+        let len = bytes_value.len();
+
+        // Negative values have no uint256 representation
+        if sign == Sign::Minus || len > 32 {
+            return Err(ReportError::InvalidLength("uint256"));
+        }
+
+        buffer[32 - len..32].copy_from_slice(&bytes_value);
+        Ok(buffer)
+    }
+
+    /// Reads a full 32-byte word at `offset` as a two's-complement signed 256-bit integer.
+    pub fn read_int256(data: &[u8], offset: usize) -> Result<BigInt, ReportError> {
+        if offset + Self::WORD_SIZE > data.len() {
+            return Err(ReportError::DataTooShort("int256"));
+        }
+        let value_bytes = &data[offset..offset + Self::WORD_SIZE];
+        Ok(BigInt::from_signed_bytes_be(value_bytes))
+    }
+
+    /// Encodes an integer within [-2^255, 2^255) as a 32-byte two's-complement word.
+    pub fn encode_int256(value: &BigInt) -> Result<[u8; 32], ReportError> {
+        // Sign-extend, so negative values read back as negative
+        let mut buffer = if value.sign() == Sign::Minus {
+            [0xffu8; 32]
+        } else {
+            [0u8; 32]
+        };
+        let bytes_value = value.to_signed_bytes_be();
+        let len = bytes_value.len();
+
+        if len > 32 {
+            return Err(ReportError::InvalidLength("int256"));
+        }
+
+        buffer[32 - len..32].copy_from_slice(&bytes_value);
+        Ok(buffer)
+    }
+
     pub(crate) fn read_uint32(data: &[u8], offset: usize) -> Result<u32, ReportError> {
         if offset + Self::WORD_SIZE > data.len() {
             return Err(ReportError::DataTooShort("uint32"));
@@ -135,3 +225,149 @@ impl ReportBase {
         Ok(buffer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_error_variant() {
+        let too_short = ReportBase::read_int192(&[0u8; 16], 0).unwrap_err();
+        assert_eq!(too_short, ReportError::DataTooShort("int192"));
+        assert_eq!(too_short.variant(), "DataTooShort");
+        assert_ne!(too_short, ReportError::InvalidLength("int192"));
+
+        let unsupported = ReportError::UnsupportedVersion {
+            version: 99,
+            feed_id: "0x01".to_string(),
+        };
+        assert_eq!(unsupported.variant(), "UnsupportedVersion");
+        assert_eq!(
+            unsupported.to_string(),
+            "Unsupported report version 99 (feed ID 0x01)"
+        );
+    }
+
+    #[test]
+    fn test_int192_round_trip() {
+        let int192_min = -(BigInt::from(1) << 191usize);
+        let int192_max = (BigInt::from(1) << 191usize) - 1;
+
+        for value in [BigInt::from(-227), BigInt::from(-1), int192_min, int192_max] {
+            let encoded = ReportBase::encode_int192(&value).unwrap();
+            assert_eq!(ReportBase::read_int192(&encoded, 0).unwrap(), value);
+        }
+
+        let encoded = ReportBase::encode_int192(&BigInt::from(-1)).unwrap();
+        assert_eq!(encoded, [0xff; 32]);
+    }
+
+    #[test]
+    fn test_read_uint192_strict() {
+        let mut word = ReportBase::encode_uint192(&BigInt::from(10)).unwrap();
+        assert_eq!(
+            ReportBase::read_uint192_strict(&word, 0).unwrap(),
+            BigInt::from(10)
+        );
+
+        // The lenient reader drops the padding, the strict one rejects it
+        word[0] = 0x01;
+        assert_eq!(
+            ReportBase::read_uint192(&word, 0).unwrap(),
+            BigInt::from(10)
+        );
+        assert!(matches!(
+            ReportBase::read_uint192_strict(&word, 0),
+            Err(ReportError::InvalidValue("uint192"))
+        ));
+
+        assert!(matches!(
+            ReportBase::read_uint192_strict(&[0u8; 31], 0),
+            Err(ReportError::DataTooShort("uint192"))
+        ));
+    }
+
+    #[test]
+    fn test_int256_round_trip() {
+        let int256_min: BigInt = -(BigInt::from(1) << 255usize);
+        let int256_max: BigInt = (BigInt::from(1) << 255usize) - 1;
+
+        for value in [
+            BigInt::from(0),
+            BigInt::from(-1),
+            BigInt::from(-227),
+            int256_min.clone(),
+            int256_min.clone() + 1,
+            int256_max.clone(),
+            int256_max.clone() - 1,
+        ] {
+            let encoded = ReportBase::encode_int256(&value).unwrap();
+            assert_eq!(ReportBase::read_int256(&encoded, 0).unwrap(), value);
+        }
+
+        let encoded = ReportBase::encode_int256(&int256_min).unwrap();
+        assert_eq!(encoded[0], 0x80);
+        assert!(encoded[1..].iter().all(|b| *b == 0));
+
+        let encoded = ReportBase::encode_int256(&BigInt::from(-1)).unwrap();
+        assert_eq!(encoded, [0xff; 32]);
+    }
+
+    #[test]
+    fn test_int256_out_of_range() {
+        let too_large = BigInt::from(1) << 255usize;
+        let too_small = -(BigInt::from(1) << 255usize) - 1;
+
+        assert!(matches!(
+            ReportBase::encode_int256(&too_large),
+            Err(ReportError::InvalidLength("int256"))
+        ));
+        assert!(matches!(
+            ReportBase::encode_int256(&too_small),
+            Err(ReportError::InvalidLength("int256"))
+        ));
+        assert!(matches!(
+            ReportBase::read_int256(&[0u8; 31], 0),
+            Err(ReportError::DataTooShort("int256"))
+        ));
+    }
+
+    #[test]
+    fn test_uint256_round_trip() {
+        let two_pow_255 = BigInt::from(1) << 255;
+        let uint256_max = (BigInt::from(1) << 256) - 1;
+
+        for value in [BigInt::from(0), two_pow_255, uint256_max] {
+            let encoded = ReportBase::encode_uint256(&value).unwrap();
+            assert_eq!(ReportBase::read_uint256(&encoded, 0).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_uint256_layout() {
+        let two_pow_255 = BigInt::from(1) << 255;
+        let encoded = ReportBase::encode_uint256(&two_pow_255).unwrap();
+        assert_eq!(encoded[0], 0x80);
+        assert!(encoded[1..].iter().all(|b| *b == 0));
+
+        let uint256_max = ReportBase::read_uint256(&[0xff; 32], 0).unwrap();
+        assert_eq!(uint256_max, (BigInt::from(1) << 256) - 1);
+    }
+
+    #[test]
+    fn test_uint256_out_of_range() {
+        let too_large = BigInt::from(1) << 256;
+        assert!(matches!(
+            ReportBase::encode_uint256(&too_large),
+            Err(ReportError::InvalidLength("uint256"))
+        ));
+        assert!(matches!(
+            ReportBase::encode_uint256(&BigInt::from(-1)),
+            Err(ReportError::InvalidLength("uint256"))
+        ));
+        assert!(matches!(
+            ReportBase::read_uint256(&[0u8; 31], 0),
+            Err(ReportError::DataTooShort("uint256"))
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+//! Compact, versioned binary encoding of decoded report data.
+//!
+//! ABI encoding pads every field to a 32-byte word. The compact encoding keeps only the bytes
+//! each field's type needs, which makes it roughly half the size, while staying a direct
+//! function of the on-chain schema.
+//!
+//! # Wire format (version 1)
+//!
+//! | Bytes | Content |
+//! |-------|---------|
+//! | 1 | Wire format version, `1` |
+//! | 2 | Report schema version, big-endian `u16` |
+//! | rest | The schema's fields in ABI order (see [`schema`](super::schema::schema)), each big-endian with a fixed width per type |
+//!
+//! Field widths: `bytes32` 32 bytes, `uint32` 4 bytes, `uint64`/`int64` 8 bytes,
+//! `uint192`/`int192` 24 bytes (two's complement for signed types), `uint256` 32 bytes.
+//!
+//! The format is frozen: any change to it is released as a new wire format version, and
+//! `from_bytes` keeps decoding every earlier version.
+
+use super::base::ReportError;
+use super::schema::{schema, FieldType};
+use super::ReportData;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+/// The wire format version written by [`ReportData::to_bytes`].
+pub const WIRE_FORMAT_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 3;
+const WORD_SIZE: usize = 32;
+
+impl FieldType {
+    /// Number of bytes the field occupies in the compact encoding.
+    fn compact_width(&self) -> usize {
+        match self {
+            FieldType::Uint32 => 4,
+            FieldType::Uint64 | FieldType::Int64 => 8,
+            FieldType::Uint192 | FieldType::Int192 => 24,
+            FieldType::Bytes32 | FieldType::Uint256 => 32,
+        }
+    }
+
+    fn is_signed(&self) -> bool {
+        matches!(self, FieldType::Int64 | FieldType::Int192)
+    }
+}
+
+impl ReportData {
+    /// Encodes the report data in the compact binary format described in the
+    /// [module documentation](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReportError` if a field does not fit its schema type.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ReportError> {
+        let blob = self.abi_encode()?;
+        let version = self.version();
+        let descriptor = schema(version).ok_or_else(|| ReportError::UnsupportedVersion {
+            version,
+            feed_id: self.feed_id().to_hex_string(),
+        })?;
+
+        let mut bytes = Vec::with_capacity(blob.len());
+        bytes.push(WIRE_FORMAT_VERSION);
+        bytes.extend_from_slice(&version.to_be_bytes());
+
+        for ((_, field_type), word) in descriptor.fields.iter().zip(blob.chunks(WORD_SIZE)) {
+            bytes.extend_from_slice(&word[WORD_SIZE - field_type.compact_width()..]);
+        }
+
+        Ok(bytes)
+    }
+
+    /// Decodes report data written by [`ReportData::to_bytes`] in this or any earlier
+    /// wire format version.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReportError` if the wire format or schema version is unknown, or the
+    /// input is truncated.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ReportData, ReportError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(ReportError::DataTooShort("compact report header"));
+        }
+
+        if bytes[0] != WIRE_FORMAT_VERSION {
+            return Err(ReportError::ParseError(
+                "compact report wire format version",
+            ));
+        }
+
+        let version = u16::from_be_bytes([bytes[1], bytes[2]]);
+        let mut fields = &bytes[HEADER_LEN..];
+        let descriptor = schema(version).ok_or_else(|| ReportError::UnsupportedVersion {
+            version,
+            feed_id: format!("0x{}", hex::encode(&fields[..fields.len().min(WORD_SIZE)])),
+        })?;
+
+        let mut blob = Vec::with_capacity(descriptor.byte_len());
+
+        for (_, field_type) in descriptor.fields {
+            let width = field_type.compact_width();
+            if fields.len() < width {
+                return Err(ReportError::DataTooShort("compact report"));
+            }
+            let (value, rest) = fields.split_at(width);
+
+            let negative = field_type.is_signed() && value[0] & 0x80 != 0;
+            let padding = if negative { 0xff } else { 0x00 };
+            blob.resize(blob.len() + WORD_SIZE - width, padding);
+            blob.extend_from_slice(value);
+
+            fields = rest;
+        }
+
+        if !fields.is_empty() {
+            return Err(ReportError::ParseError("compact report length"));
+        }
+
+        ReportData::decode(version, &blob)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::{
+        generate_mock_report_data_v1, generate_mock_report_data_v10, generate_mock_report_data_v11,
+        generate_mock_report_data_v12, generate_mock_report_data_v13, generate_mock_report_data_v2,
+        generate_mock_report_data_v3, generate_mock_report_data_v4, generate_mock_report_data_v5,
+        generate_mock_report_data_v6, generate_mock_report_data_v7, generate_mock_report_data_v8,
+        generate_mock_report_data_v9,
+    };
+    use num_bigint::BigInt;
+
+    /// `ReportData::V3(generate_mock_report_data_v3())` with a negative bid, as written by wire
+    /// format version 1. Must keep decoding in every future release.
+    const V3_WIRE_FORMAT_1: &str = "01000300036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b847266741d8c66741d8c00000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000a66741df0000000000000000000000000000000000000000000000064ffffffffffffffffffffffffffffffffffffffffffffff1d00000000000000000000000000000000000000000000006e";
+
+    fn mock_report_data() -> Vec<ReportData> {
+        vec![
+            ReportData::V1(generate_mock_report_data_v1()),
+            ReportData::V2(generate_mock_report_data_v2()),
+            ReportData::V3(generate_mock_report_data_v3()),
+            ReportData::V4(generate_mock_report_data_v4()),
+            ReportData::V5(generate_mock_report_data_v5()),
+            ReportData::V6(generate_mock_report_data_v6()),
+            ReportData::V7(generate_mock_report_data_v7()),
+            ReportData::V8(generate_mock_report_data_v8()),
+            ReportData::V9(generate_mock_report_data_v9()),
+            ReportData::V10(generate_mock_report_data_v10()),
+            ReportData::V11(generate_mock_report_data_v11()),
+            ReportData::V12(generate_mock_report_data_v12()),
+            ReportData::V13(generate_mock_report_data_v13()),
+        ]
+    }
+
+    fn negative_v3() -> ReportData {
+        let mut report_data = generate_mock_report_data_v3();
+        report_data.bid = BigInt::from(-227);
+        ReportData::V3(report_data)
+    }
+
+    #[test]
+    fn test_compact_round_trip() {
+        for report_data in mock_report_data().into_iter().chain([negative_v3()]) {
+            let bytes = report_data.to_bytes().unwrap();
+            let decoded = ReportData::from_bytes(&bytes).unwrap();
+
+            assert_eq!(decoded, report_data);
+            assert!(bytes.len() < report_data.abi_encode().unwrap().len());
+        }
+    }
+
+    #[test]
+    fn test_compact_wire_format_1_compatibility() {
+        let bytes = hex::decode(V3_WIRE_FORMAT_1).unwrap();
+
+        assert_eq!(negative_v3().to_bytes().unwrap(), bytes);
+
+        match ReportData::from_bytes(&bytes).unwrap() {
+            ReportData::V3(report_data) => {
+                assert_eq!(report_data.bid, BigInt::from(-227));
+                assert_eq!(report_data.ask, BigInt::from(110));
+            }
+            other => panic!("Expected ReportData::V3, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_compact_rejects_malformed_input() {
+        let bytes = negative_v3().to_bytes().unwrap();
+
+        assert!(matches!(
+            ReportData::from_bytes(&bytes[..2]),
+            Err(ReportError::DataTooShort(_))
+        ));
+        assert!(matches!(
+            ReportData::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(ReportError::DataTooShort(_))
+        ));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            ReportData::from_bytes(&trailing),
+            Err(ReportError::ParseError(_))
+        ));
+
+        let mut unknown_format = bytes.clone();
+        unknown_format[0] = 0xff;
+        assert!(matches!(
+            ReportData::from_bytes(&unknown_format),
+            Err(ReportError::ParseError(_))
+        ));
+
+        let mut unknown_schema = bytes;
+        unknown_schema[2] = 0xff;
+        assert!(matches!(
+            ReportData::from_bytes(&unknown_schema),
+            Err(ReportError::UnsupportedVersion { version: 0xff, .. })
+        ));
+    }
+}
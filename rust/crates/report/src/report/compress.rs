@@ -1,21 +1,36 @@
 use super::Report;
 
-use snap::raw::Encoder;
+use snap::raw::{Decoder, Encoder};
+use thiserror::Error;
 
-#[derive(Debug)]
+/// Format version written as the first byte of every compressed payload.
+///
+/// Version `1` is followed by a block in the [Snappy raw format](https://github.com/google/snappy/blob/main/format_description.txt).
+/// Any change to the compression is released as a new version, so a payload is never decoded
+/// with the wrong algorithm.
+pub const COMPRESSION_FORMAT_VERSION: u8 = 1;
+
+#[derive(Debug, Error)]
 pub enum CompressionError {
-    SerializationError(serde_json::Error),
-    CompressionError(snap::Error),
-}
+    #[error("Report JSON error: {0}")]
+    SerializationError(#[source] serde_json::Error),
 
-impl From<snap::Error> for CompressionError {
-    fn from(err: snap::Error) -> Self {
-        CompressionError::CompressionError(err)
-    }
+    #[error("Snappy error: {0}")]
+    CompressionError(#[from] snap::Error),
+
+    /// The payload starts with a format version this release cannot decode.
+    #[error("Unsupported compression format version {0}")]
+    UnsupportedCompressionVersion(u8),
+
+    /// The payload is empty, so it has no format version.
+    #[error("Compressed payload is empty")]
+    MissingCompressionVersion,
 }
 
 /// Compresses a raw report payload using the [Snappy compression algorithm](https://google.github.io/snappy/).
 ///
+/// The output starts with a one-byte [`COMPRESSION_FORMAT_VERSION`] tag.
+///
 /// # Arguments
 ///
 /// * `payload` - The raw report payload to compress.
@@ -39,7 +54,11 @@ impl From<snap::Error> for CompressionError {
 /// ```
 pub fn compress_report_raw(payload: &[u8]) -> Result<Vec<u8>, CompressionError> {
     let mut encoder = Encoder::new();
-    let compressed = encoder.compress_vec(payload)?;
+    let mut compressed = vec![0u8; 1 + snap::raw::max_compress_len(payload.len())];
+    compressed[0] = COMPRESSION_FORMAT_VERSION;
+
+    let len = encoder.compress(payload, &mut compressed[1..])?;
+    compressed.truncate(1 + len);
     Ok(compressed)
 }
 
@@ -80,12 +99,53 @@ pub fn compress_report(report: Report) -> Result<Vec<u8>, CompressionError> {
     compress_report_raw(&payload)
 }
 
+/// Decompresses a payload produced by [`compress_report_raw`].
+///
+/// Fails with `CompressionError::UnsupportedCompressionVersion` if the payload was written in a
+/// format version other than [`COMPRESSION_FORMAT_VERSION`].
+///
+/// # Arguments
+///
+/// * `payload` - The Snappy-compressed payload.
+///
+/// # Returns
+///
+/// A `Result` containing the decompressed payload as a `Vec<u8>` if successful, or a `CompressionError` error if decompression fails.
+pub fn decompress_report_raw(payload: &[u8]) -> Result<Vec<u8>, CompressionError> {
+    let (&version, compressed) = payload
+        .split_first()
+        .ok_or(CompressionError::MissingCompressionVersion)?;
+
+    if version != COMPRESSION_FORMAT_VERSION {
+        return Err(CompressionError::UnsupportedCompressionVersion(version));
+    }
+
+    let mut decoder = Decoder::new();
+    let decompressed = decoder.decompress_vec(compressed)?;
+    Ok(decompressed)
+}
+
+/// Decompresses a report produced by [`compress_report`].
+///
+/// # Arguments
+///
+/// * `payload` - The Snappy-compressed report.
+///
+/// # Returns
+///
+/// A `Result` containing the `Report` if successful, or a `CompressionError` error if decompression or deserialization fails.
+pub fn decompress_report(payload: &[u8]) -> Result<Report, CompressionError> {
+    let decompressed = decompress_report_raw(payload)?;
+
+    serde_json::from_slice(&decompressed).map_err(CompressionError::SerializationError)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::feed_id::ID;
 
-    const COMPRESSED_MOCK_REPORT: &str = "e210f0817b22666565644944223a22307830303033366234616137653537636137623638616531626634353635336635366236353666643361613333356566376661653639366236363366316238343732222c2276616c696446726f6d54696d657374616d70223a313731383838353737322c226f62736572766174696f6e7354696d6573744223002466756c6c5265706f72740195f0403030366264383738333064356633333665323035636635633633333239613164616238663564353638313265616562376336393330306536366162386532323030da02001863663765643133da3e00fe01000d010065f64800eaff00050101fe1031303130300d06fe0100be01000431320172f03c333061623764303266626261396336333034663938383234353234343037623166343934373431313734333230636664313761326332326565633164650141ce01000c363661384166ce3c000101ee4000283537383130363533646439014bba01002c35343133313564613736643621bbd601001836366161343734c6000138396136393765653432333033353034c27e002c396136353036643134323664c23d003c30303039613737643033616533353566fa4003f48101303036373262616339393166353233336466383966353831646330326138396464386434383431396533353538623234376433653635663430363966613435633336363538613561343832306463393466633437613838613231643833343734633239656533383338326334366236663961353735623963653862653465363839633033633736666163313966626563346132396462613730346337326363303033613662653166393661663131356533323233323166303638386532343732306135643962643731333661316439363834326563383931333330353862383838623265363537326235643431313464653234323631393565303338663163396135636535303031366236663561356465303765303835323962383435653163363232646362656661306366613266666431323865393933326563656538656664383639626335366430396135306365623336306138643336366366613865656665336636343237396338386264626338383735363065666139393434323338656221d2ee0100f4820136306532613830306631363966323631363435333363376661666636633930373363643664623234306438393434346433343837313133323332663963333134323261303939336262343764353638303764306463323637323865346338343234626239646237373531313030313930343335336631303232313638373233303130633436363237633839306265366537303165373636363739363030363936383636633838386563383065376462643432386635313632613234663264383236326638343662646230366439653436643239356464386538393666623233326265383035333462303034313636306665343435306137656465396263336232333037323233383137373361346165383132343135363838363761373539663533633262646430356433326232303965373838343566633538323033393439653530613630383934326232373063343536303031653537383232376164303038363163663566343762323762303931333761306334623766386234373436636566227d";
+    const COMPRESSED_MOCK_REPORT: &str = "01e210f0817b22666565644944223a22307830303033366234616137653537636137623638616531626634353635336635366236353666643361613333356566376661653639366236363366316238343732222c2276616c696446726f6d54696d657374616d70223a313731383838353737322c226f62736572766174696f6e7354696d6573744223002466756c6c5265706f72740195f0403030366264383738333064356633333665323035636635633633333239613164616238663564353638313265616562376336393330306536366162386532323030da02001863663765643133da3e00fe01000d010065f64800eaff00050101fe1031303130300d06fe0100be01000431320172f03c333061623764303266626261396336333034663938383234353234343037623166343934373431313734333230636664313761326332326565633164650141ce01000c363661384166ce3c000101ee4000283537383130363533646439014bba01002c35343133313564613736643621bbd601001836366161343734c6000138396136393765653432333033353034c27e002c396136353036643134323664c23d003c30303039613737643033616533353566fa4003f48101303036373262616339393166353233336466383966353831646330326138396464386434383431396533353538623234376433653635663430363966613435633336363538613561343832306463393466633437613838613231643833343734633239656533383338326334366236663961353735623963653862653465363839633033633736666163313966626563346132396462613730346337326363303033613662653166393661663131356533323233323166303638386532343732306135643962643731333661316439363834326563383931333330353862383838623265363537326235643431313464653234323631393565303338663163396135636535303031366236663561356465303765303835323962383435653163363232646362656661306366613266666431323865393933326563656538656664383639626335366430396135306365623336306138643336366366613865656665336636343237396338386264626338383735363065666139393434323338656221d2ee0100f4820136306532613830306631363966323631363435333363376661666636633930373363643664623234306438393434346433343837313133323332663963333134323261303939336262343764353638303764306463323637323865346338343234626239646237373531313030313930343335336631303232313638373233303130633436363237633839306265366537303165373636363739363030363936383636633838386563383065376462643432386635313632613234663264383236326638343662646230366439653436643239356464386538393666623233326265383035333462303034313636306665343435306137656465396263336232333037323233383137373361346165383132343135363838363761373539663533633262646430356433326232303965373838343566633538323033393439653530613630383934326232373063343536303031653537383232376164303038363163663566343762323762303931333761306334623766386234373436636566227d";
 
     #[test]
     fn compress_report_raw_test() {
@@ -120,4 +180,90 @@ mod tests {
 
         assert_eq!(hex::encode(&got), want);
     }
+
+    #[test]
+    fn decompress_report_test() {
+        let compressed = hex::decode(COMPRESSED_MOCK_REPORT).unwrap();
+
+        let report = decompress_report(&compressed).unwrap();
+        assert_eq!(
+            report.feed_id,
+            ID::from_hex_str("0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472")
+                .unwrap()
+        );
+        assert_eq!(report.observations_timestamp, 1718885772);
+
+        assert_eq!(compress_report(report).unwrap(), compressed);
+    }
+
+    #[test]
+    fn compress_decompress_round_trip_test() {
+        let report = Report {
+            feed_id: ID::from_hex_str(
+                "0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472",
+            )
+            .unwrap(),
+            valid_from_timestamp: 1718885772,
+            observations_timestamp: 1718885773,
+            full_report: format!("0x{}", "00".repeat(256)),
+        };
+
+        let compressed = compress_report(report.clone()).unwrap();
+        assert_eq!(decompress_report(&compressed).unwrap(), report);
+
+        let payload = serde_json::to_vec(&report).unwrap();
+        let compressed = compress_report_raw(&payload).unwrap();
+        assert!(compressed.len() < payload.len());
+        assert_eq!(decompress_report_raw(&compressed).unwrap(), payload);
+    }
+
+    #[test]
+    fn decompress_invalid_payload_test() {
+        assert!(matches!(
+            decompress_report_raw(b"\x01not snappy"),
+            Err(CompressionError::CompressionError(_))
+        ));
+
+        let not_a_report = compress_report_raw(b"[]").unwrap();
+        assert!(matches!(
+            decompress_report(&not_a_report),
+            Err(CompressionError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn compression_format_version_test() {
+        let compressed = compress_report_raw(b"payload").unwrap();
+        assert_eq!(compressed[0], COMPRESSION_FORMAT_VERSION);
+
+        let mut unknown_version = compressed.clone();
+        unknown_version[0] = 0xff;
+        assert!(matches!(
+            decompress_report_raw(&unknown_version),
+            Err(CompressionError::UnsupportedCompressionVersion(0xff))
+        ));
+        assert!(matches!(
+            decompress_report(&unknown_version),
+            Err(CompressionError::UnsupportedCompressionVersion(0xff))
+        ));
+
+        // Payloads from before the tag was introduced are rejected rather than misread
+        let untagged = &compressed[1..];
+        assert!(matches!(
+            decompress_report_raw(untagged),
+            Err(CompressionError::UnsupportedCompressionVersion(_))
+        ));
+
+        assert!(matches!(
+            decompress_report_raw(&[]),
+            Err(CompressionError::MissingCompressionVersion)
+        ));
+
+        assert_eq!(
+            decompress_report_raw(&unknown_version)
+                .unwrap_err()
+                .to_string(),
+            "Unsupported compression format version 255"
+        );
+    }
 }
@@ -0,0 +1,72 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use num_bigint::{BigInt, Sign};
+
+/// Renders a fixed-point integer as a decimal string with exactly `decimals` fractional digits.
+///
+/// # Parameters
+///
+/// - `value`: The raw fixed-point value, as decoded from a report.
+/// - `decimals`: The number of decimal places the value is carried to.
+///
+/// # Examples
+///
+/// ```rust
+/// use chainlink_data_streams_report::report::decimal::to_decimal;
+/// use num_bigint::BigInt;
+///
+/// assert_eq!(to_decimal(&BigInt::from(123456789), 8), "1.23456789");
+/// assert_eq!(to_decimal(&BigInt::from(-5), 2), "-0.05");
+/// ```
+pub fn to_decimal(value: &BigInt, decimals: u32) -> String {
+    let digits = value.magnitude().to_string();
+    let sign = if value.sign() == Sign::Minus { "-" } else { "" };
+
+    if decimals == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", digits, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_decimal() {
+        assert_eq!(to_decimal(&BigInt::from(0), 8), "0.00000000");
+        assert_eq!(to_decimal(&BigInt::from(100), 8), "0.00000100");
+        assert_eq!(
+            to_decimal(&BigInt::from(6_954_102_420_i64), 8),
+            "69.54102420"
+        );
+        assert_eq!(
+            to_decimal(&BigInt::from(-6_954_102_420_i64), 8),
+            "-69.54102420"
+        );
+        assert_eq!(
+            to_decimal(&BigInt::from(695_410_242_043_786_500_i64), 18),
+            "0.695410242043786500"
+        );
+        assert_eq!(to_decimal(&BigInt::from(42), 0), "42");
+    }
+
+    #[test]
+    fn test_to_decimal_negative_18_decimals() {
+        assert_eq!(
+            to_decimal(&BigInt::from(-695_410_242_043_786_500_i64), 18),
+            "-0.695410242043786500"
+        );
+        assert_eq!(to_decimal(&BigInt::from(-1), 18), "-0.000000000000000001");
+
+        // Beyond the range of i64, as int192 prices can be
+        let price: BigInt = "-123456789012345678901234567890".parse().unwrap();
+        assert_eq!(to_decimal(&price, 18), "-123456789012.345678901234567890");
+        assert_eq!(to_decimal(&price, 8), "-1234567890123456789012.34567890");
+    }
+}
@@ -0,0 +1,123 @@
+//! Typed market status for the report schemas that carry one (v4, v8, v10 and v11).
+
+use crate::report::{
+    v10::ReportDataV10, v11::ReportDataV11, v4::ReportDataV4, v8::ReportDataV8, ReportData,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The DON's consensus on whether the market for a feed's asset is open.
+///
+/// Decoded from a report's `market_status` field. Values other than the documented `0`
+/// (`Unknown`), `1` (`Closed`) and `2` (`Open`) are treated as `Unknown`, keeping the raw value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketStatus {
+    Unknown(u32),
+    Closed,
+    Open,
+}
+
+impl MarketStatus {
+    /// Returns `true` if the market is open. An `Unknown` status is not considered open.
+    pub fn is_open(&self) -> bool {
+        *self == MarketStatus::Open
+    }
+}
+
+impl From<u32> for MarketStatus {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => MarketStatus::Closed,
+            2 => MarketStatus::Open,
+            other => MarketStatus::Unknown(other),
+        }
+    }
+}
+
+impl From<u8> for MarketStatus {
+    fn from(value: u8) -> Self {
+        MarketStatus::from(u32::from(value))
+    }
+}
+
+macro_rules! impl_market_status {
+    ($($report:ty),* $(,)?) => {
+        $(
+            impl $report {
+                /// Returns the report's `market_status` as a [`MarketStatus`].
+                pub fn market_status(&self) -> MarketStatus {
+                    MarketStatus::from(self.market_status)
+                }
+            }
+        )*
+    };
+}
+
+impl_market_status!(ReportDataV4, ReportDataV8, ReportDataV10, ReportDataV11);
+
+impl ReportData {
+    /// Returns the report's market status, or `None` for schemas without a `market_status` field.
+    pub fn market_status(&self) -> Option<MarketStatus> {
+        match self {
+            ReportData::V4(report_data) => Some(report_data.market_status()),
+            ReportData::V8(report_data) => Some(report_data.market_status()),
+            ReportData::V10(report_data) => Some(report_data.market_status()),
+            ReportData::V11(report_data) => Some(report_data.market_status()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::{
+        generate_mock_report_data_v3, generate_mock_report_data_v4, generate_mock_report_data_v8,
+    };
+
+    #[test]
+    fn test_market_status_from() {
+        assert_eq!(MarketStatus::from(0u32), MarketStatus::Unknown(0));
+        assert_eq!(MarketStatus::from(1u32), MarketStatus::Closed);
+        assert_eq!(MarketStatus::from(2u32), MarketStatus::Open);
+        assert_eq!(MarketStatus::from(2u8), MarketStatus::Open);
+
+        // Out-of-range values are never mistaken for an open market
+        assert_eq!(MarketStatus::from(3u32), MarketStatus::Unknown(3));
+        assert_eq!(
+            MarketStatus::from(u32::MAX),
+            MarketStatus::Unknown(u32::MAX)
+        );
+        assert_eq!(MarketStatus::from(u8::MAX), MarketStatus::Unknown(255));
+
+        assert!(MarketStatus::Open.is_open());
+        assert!(!MarketStatus::Closed.is_open());
+        assert!(!MarketStatus::Unknown(0).is_open());
+    }
+
+    #[test]
+    fn test_report_market_status() {
+        let mut v4 = generate_mock_report_data_v4();
+        assert!(v4.market_status().is_open());
+
+        v4.market_status = 7;
+        assert_eq!(v4.market_status(), MarketStatus::Unknown(7));
+        assert_eq!(v4.market_status, 7);
+
+        v4.market_status = 1;
+        assert_eq!(v4.market_status(), MarketStatus::Closed);
+        assert_eq!(
+            ReportData::V4(v4).market_status(),
+            Some(MarketStatus::Closed)
+        );
+
+        assert_eq!(
+            ReportData::V8(generate_mock_report_data_v8()).market_status(),
+            Some(MarketStatus::Open)
+        );
+        assert_eq!(
+            ReportData::V3(generate_mock_report_data_v3()).market_status(),
+            None
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Typed ripcord flag for the NAV report schemas (v9 and v12).
+
+use crate::report::{v12::ReportDataV12, v9::ReportDataV9};
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a NAV report's data provider paused reporting.
+///
+/// Decoded from a report's `ripcord` field. `0` is `Normal`. Any other value is treated as
+/// `Paused`, so an unexpected flag never lets paused NAV data through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Ripcord {
+    /// The data provider is operating normally, the NAV data can be consumed.
+    Normal,
+    /// The data provider paused reporting. **Do not consume the NAV data.**
+    Paused,
+}
+
+impl From<u32> for Ripcord {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Ripcord::Normal,
+            _ => Ripcord::Paused,
+        }
+    }
+}
+
+macro_rules! impl_ripcord {
+    ($($report:ty),* $(,)?) => {
+        $(
+            impl $report {
+                /// Returns the report's `ripcord` flag as a [`Ripcord`].
+                pub fn ripcord(&self) -> Ripcord {
+                    Ripcord::from(self.ripcord)
+                }
+
+                /// Returns `true` if the ripcord is not pulled, i.e. the NAV data can be consumed.
+                pub fn is_safe_to_consume(&self) -> bool {
+                    self.ripcord == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_ripcord!(ReportDataV9, ReportDataV12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::{generate_mock_report_data_v12, generate_mock_report_data_v9};
+
+    #[test]
+    fn test_ripcord_from() {
+        assert_eq!(Ripcord::from(0), Ripcord::Normal);
+        assert_eq!(Ripcord::from(1), Ripcord::Paused);
+        assert_eq!(Ripcord::from(u32::MAX), Ripcord::Paused);
+    }
+
+    #[test]
+    fn test_is_safe_to_consume() {
+        let mut v9 = generate_mock_report_data_v9();
+        assert!(v9.is_safe_to_consume());
+        assert_eq!(v9.ripcord(), Ripcord::Normal);
+
+        v9.ripcord = 1;
+        assert!(!v9.is_safe_to_consume());
+        assert_eq!(v9.ripcord(), Ripcord::Paused);
+
+        let mut v12 = generate_mock_report_data_v12();
+        assert!(v12.is_safe_to_consume());
+
+        v12.ripcord = 2;
+        assert!(!v12.is_safe_to_consume());
+        assert_eq!(v12.ripcord(), Ripcord::Paused);
+    }
+}
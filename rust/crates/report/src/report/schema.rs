@@ -0,0 +1,393 @@
+use crate::report::{
+    v1::ReportDataV1, v10::ReportDataV10, v11::ReportDataV11, v12::ReportDataV12,
+    v13::ReportDataV13, v2::ReportDataV2, v3::ReportDataV3, v4::ReportDataV4, v5::ReportDataV5,
+    v6::ReportDataV6, v7::ReportDataV7, v8::ReportDataV8, v9::ReportDataV9,
+};
+
+/// The Solidity type of a report field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    Bytes32,
+    Uint32,
+    Uint64,
+    Int64,
+    Uint192,
+    Int192,
+    Uint256,
+}
+
+impl FieldType {
+    /// Returns the Solidity name of the type, e.g. `"int192"`.
+    pub fn solidity_name(&self) -> &'static str {
+        match self {
+            FieldType::Bytes32 => "bytes32",
+            FieldType::Uint32 => "uint32",
+            FieldType::Uint64 => "uint64",
+            FieldType::Int64 => "int64",
+            FieldType::Uint192 => "uint192",
+            FieldType::Int192 => "int192",
+            FieldType::Uint256 => "uint256",
+        }
+    }
+}
+
+/// The layout of a report schema: its fields, in ABI encoding order.
+///
+/// Every field is a static type and occupies exactly one 32-byte word.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SchemaDescriptor {
+    /// The report schema version.
+    pub version: u16,
+    /// The field names, as used by the `ReportDataVx` structs, and their types.
+    pub fields: &'static [(&'static str, FieldType)],
+}
+
+impl SchemaDescriptor {
+    /// Returns the number of fields in the schema.
+    pub fn field_count(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns the number of 32-byte words the ABI-encoded report blob occupies.
+    pub fn word_count(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns the size of the ABI-encoded report blob in bytes.
+    pub fn byte_len(&self) -> usize {
+        self.word_count() * 32
+    }
+}
+
+/// Returns the schema of a built-in report version, or `None` if the version is unknown.
+///
+/// # Examples
+///
+/// ```rust
+/// use chainlink_data_streams_report::report::schema::{schema, FieldType};
+///
+/// let v3 = schema(3).unwrap();
+/// assert_eq!(v3.word_count(), 9);
+/// assert_eq!(v3.fields[6], ("benchmark_price", FieldType::Int192));
+///
+/// assert!(schema(99).is_none());
+/// ```
+pub fn schema(version: u16) -> Option<&'static SchemaDescriptor> {
+    match version {
+        1 => Some(&V1),
+        2 => Some(&V2),
+        3 => Some(&V3),
+        4 => Some(&V4),
+        5 => Some(&V5),
+        6 => Some(&V6),
+        7 => Some(&V7),
+        8 => Some(&V8),
+        9 => Some(&V9),
+        10 => Some(&V10),
+        11 => Some(&V11),
+        12 => Some(&V12),
+        13 => Some(&V13),
+        _ => None,
+    }
+}
+
+macro_rules! impl_field_count {
+    ($($report:ty => $schema:ident,)*) => {
+        $(
+            impl $report {
+                /// Returns the number of fields in this schema, each occupying one 32-byte word.
+                pub fn field_count() -> usize {
+                    $schema.field_count()
+                }
+            }
+        )*
+    };
+}
+
+impl_field_count! {
+    ReportDataV1 => V1,
+    ReportDataV2 => V2,
+    ReportDataV3 => V3,
+    ReportDataV4 => V4,
+    ReportDataV5 => V5,
+    ReportDataV6 => V6,
+    ReportDataV7 => V7,
+    ReportDataV8 => V8,
+    ReportDataV9 => V9,
+    ReportDataV10 => V10,
+    ReportDataV11 => V11,
+    ReportDataV12 => V12,
+    ReportDataV13 => V13,
+}
+
+static V1: SchemaDescriptor = SchemaDescriptor {
+    version: 1,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("benchmark_price", FieldType::Int192),
+        ("bid", FieldType::Int192),
+        ("ask", FieldType::Int192),
+        ("current_block_num", FieldType::Uint64),
+        ("current_block_hash", FieldType::Bytes32),
+        ("valid_from_block_num", FieldType::Uint64),
+        ("current_block_timestamp", FieldType::Uint64),
+    ],
+};
+
+static V2: SchemaDescriptor = SchemaDescriptor {
+    version: 2,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("benchmark_price", FieldType::Int192),
+    ],
+};
+
+static V3: SchemaDescriptor = SchemaDescriptor {
+    version: 3,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("benchmark_price", FieldType::Int192),
+        ("bid", FieldType::Int192),
+        ("ask", FieldType::Int192),
+    ],
+};
+
+static V4: SchemaDescriptor = SchemaDescriptor {
+    version: 4,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("price", FieldType::Int192),
+        ("market_status", FieldType::Uint32),
+    ],
+};
+
+static V5: SchemaDescriptor = SchemaDescriptor {
+    version: 5,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("rate", FieldType::Int192),
+        ("timestamp", FieldType::Uint32),
+        ("duration", FieldType::Uint32),
+    ],
+};
+
+static V6: SchemaDescriptor = SchemaDescriptor {
+    version: 6,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("price", FieldType::Int192),
+        ("price2", FieldType::Int192),
+        ("price3", FieldType::Int192),
+        ("price4", FieldType::Int192),
+        ("price5", FieldType::Int192),
+    ],
+};
+
+static V7: SchemaDescriptor = SchemaDescriptor {
+    version: 7,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("exchange_rate", FieldType::Int192),
+    ],
+};
+
+static V8: SchemaDescriptor = SchemaDescriptor {
+    version: 8,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("last_update_timestamp", FieldType::Uint64),
+        ("mid_price", FieldType::Int192),
+        ("market_status", FieldType::Uint32),
+    ],
+};
+
+static V9: SchemaDescriptor = SchemaDescriptor {
+    version: 9,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("nav_per_share", FieldType::Int192),
+        ("nav_date", FieldType::Uint64),
+        ("aum", FieldType::Int192),
+        ("ripcord", FieldType::Uint32),
+    ],
+};
+
+static V10: SchemaDescriptor = SchemaDescriptor {
+    version: 10,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("last_update_timestamp", FieldType::Uint64),
+        ("price", FieldType::Int192),
+        ("market_status", FieldType::Uint32),
+        ("current_multiplier", FieldType::Int192),
+        ("new_multiplier", FieldType::Int192),
+        ("activation_date_time", FieldType::Uint32),
+        ("tokenized_price", FieldType::Int192),
+    ],
+};
+
+static V11: SchemaDescriptor = SchemaDescriptor {
+    version: 11,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("mid", FieldType::Int192),
+        ("last_seen_timestamp_ns", FieldType::Uint64),
+        ("bid", FieldType::Int192),
+        ("bid_volume", FieldType::Int192),
+        ("ask", FieldType::Int192),
+        ("ask_volume", FieldType::Int192),
+        ("last_traded_price", FieldType::Int192),
+        ("market_status", FieldType::Uint32),
+    ],
+};
+
+static V12: SchemaDescriptor = SchemaDescriptor {
+    version: 12,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("nav_per_share", FieldType::Int192),
+        ("next_nav_per_share", FieldType::Int192),
+        ("nav_date", FieldType::Int64),
+        ("ripcord", FieldType::Uint32),
+    ],
+};
+
+static V13: SchemaDescriptor = SchemaDescriptor {
+    version: 13,
+    fields: &[
+        ("feed_id", FieldType::Bytes32),
+        ("valid_from_timestamp", FieldType::Uint32),
+        ("observations_timestamp", FieldType::Uint32),
+        ("native_fee", FieldType::Uint192),
+        ("link_fee", FieldType::Uint192),
+        ("expires_at", FieldType::Uint32),
+        ("best_ask", FieldType::Int192),
+        ("best_bid", FieldType::Int192),
+        ("ask_volume", FieldType::Uint64),
+        ("bid_volume", FieldType::Uint64),
+        ("last_traded_price", FieldType::Int192),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::{
+        generate_mock_report_data_v1, generate_mock_report_data_v10, generate_mock_report_data_v11,
+        generate_mock_report_data_v12, generate_mock_report_data_v13, generate_mock_report_data_v2,
+        generate_mock_report_data_v3, generate_mock_report_data_v4, generate_mock_report_data_v5,
+        generate_mock_report_data_v6, generate_mock_report_data_v7, generate_mock_report_data_v8,
+        generate_mock_report_data_v9,
+    };
+
+    #[test]
+    fn test_schema_matches_encoded_length() {
+        let cases = [
+            (V1.word_count(), generate_mock_report_data_v1().abi_encode()),
+            (V2.word_count(), generate_mock_report_data_v2().abi_encode()),
+            (V3.word_count(), generate_mock_report_data_v3().abi_encode()),
+            (V4.word_count(), generate_mock_report_data_v4().abi_encode()),
+            (V5.word_count(), generate_mock_report_data_v5().abi_encode()),
+            (V6.word_count(), generate_mock_report_data_v6().abi_encode()),
+            (V7.word_count(), generate_mock_report_data_v7().abi_encode()),
+            (V8.word_count(), generate_mock_report_data_v8().abi_encode()),
+            (V9.word_count(), generate_mock_report_data_v9().abi_encode()),
+            (
+                V10.word_count(),
+                generate_mock_report_data_v10().abi_encode(),
+            ),
+            (
+                V11.word_count(),
+                generate_mock_report_data_v11().abi_encode(),
+            ),
+            (
+                V12.word_count(),
+                generate_mock_report_data_v12().abi_encode(),
+            ),
+            (
+                V13.word_count(),
+                generate_mock_report_data_v13().abi_encode(),
+            ),
+        ];
+
+        for (word_count, encoded) in cases {
+            assert_eq!(word_count * 32, encoded.unwrap().len());
+        }
+    }
+
+    #[test]
+    fn test_schema_versions() {
+        for version in 1..=13 {
+            let schema = schema(version).unwrap();
+            assert_eq!(schema.version, version);
+            assert_eq!(schema.fields[0], ("feed_id", FieldType::Bytes32));
+        }
+
+        assert!(schema(0).is_none());
+        assert!(schema(14).is_none());
+    }
+
+    #[test]
+    fn test_field_count() {
+        assert_eq!(ReportDataV1::field_count(), 9);
+        assert_eq!(ReportDataV3::field_count(), 9);
+        assert_eq!(ReportDataV10::field_count(), 13);
+        assert_eq!(ReportDataV11::field_count(), 14);
+    }
+}
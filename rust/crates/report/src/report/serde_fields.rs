@@ -0,0 +1,104 @@
+//! Serde representations for report data fields that have no natural JSON form.
+//!
+//! Used through `#[serde(with = "...")]` on the `ReportDataVn` structs.
+
+/// Serializes a `BigInt` as a decimal string, so values beyond the range of a JSON number
+/// round-trip exactly.
+pub(crate) mod bigint_string {
+    use alloc::string::String;
+    use num_bigint::BigInt;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &BigInt,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigInt, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(Error::custom)
+    }
+}
+
+/// Serializes a `[u8; 32]` as a `0x`-prefixed hex string.
+pub(crate) mod hex_bytes32 {
+    use alloc::format;
+    use alloc::string::String;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &[u8; 32],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(value)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; 32], D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let bytes = hex::decode(s.trim_start_matches("0x")).map_err(Error::custom)?;
+        bytes
+            .try_into()
+            .map_err(|_| Error::custom("expected 32 bytes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::tests::{generate_mock_report_data_v1, generate_mock_report_data_v3};
+    use crate::report::{v1::ReportDataV1, v3::ReportDataV3};
+
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_report_data_json_round_trip() {
+        let mut report_data = generate_mock_report_data_v3();
+        // Beyond the range of f64 and u64
+        report_data.benchmark_price = -(BigInt::from(1) << 150usize);
+
+        let json = serde_json::to_value(&report_data).unwrap();
+        assert_eq!(
+            json["feed_id"],
+            serde_json::to_value(report_data.feed_id).unwrap()
+        );
+        assert_eq!(
+            json["benchmark_price"],
+            (-(BigInt::from(1) << 150usize)).to_string()
+        );
+        assert_eq!(json["expires_at"], report_data.expires_at);
+
+        let decoded: ReportDataV3 = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            decoded.abi_encode().unwrap(),
+            report_data.abi_encode().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bytes32_as_hex() {
+        let mut report_data = generate_mock_report_data_v1();
+        report_data.current_block_hash = [0xab; 32];
+
+        let json = serde_json::to_value(&report_data).unwrap();
+        assert_eq!(json["current_block_hash"], format!("0x{}", "ab".repeat(32)));
+
+        let decoded: ReportDataV1 = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.current_block_hash, [0xab; 32]);
+    }
+
+    #[test]
+    fn test_invalid_fields_are_rejected() {
+        let mut json = serde_json::to_value(generate_mock_report_data_v3()).unwrap();
+        json["bid"] = "not a number".into();
+        assert!(serde_json::from_value::<ReportDataV3>(json).is_err());
+
+        let mut json = serde_json::to_value(generate_mock_report_data_v1()).unwrap();
+        json["current_block_hash"] = "0xabcd".into();
+        assert!(serde_json::from_value::<ReportDataV1>(json).is_err());
+    }
+}
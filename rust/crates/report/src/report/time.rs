@@ -0,0 +1,293 @@
+//! Conversions from report timestamps to typed times.
+//!
+//! Every schema from V2 on carries `observations_timestamp`, `valid_from_timestamp` and
+//! `expires_at` as Unix seconds. Schema-specific fields use the unit their schema documents:
+//! V1 `current_block_timestamp`, V5 `timestamp` and V10 `activation_date_time` are seconds, while
+//! V8/V10 `last_update_timestamp`, V9/V12 `nav_date` and V11 `last_seen_timestamp_ns` are
+//! nanoseconds. The accessors added here apply the right unit per field, so callers don't have to.
+//!
+//! With the `chrono` feature enabled, each `*_time` accessor has a `*_datetime` counterpart
+//! returning a `DateTime<Utc>`.
+
+use crate::report::{
+    v1::ReportDataV1, v10::ReportDataV10, v11::ReportDataV11, v12::ReportDataV12,
+    v13::ReportDataV13, v2::ReportDataV2, v3::ReportDataV3, v4::ReportDataV4, v5::ReportDataV5,
+    v6::ReportDataV6, v7::ReportDataV7, v8::ReportDataV8, v9::ReportDataV9, Report, ReportData,
+};
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+/// Converts a Unix timestamp in seconds to a `SystemTime`.
+///
+/// Returns `None` if the timestamp is too far in the future for `SystemTime` to represent.
+pub fn from_unix_secs(secs: u64) -> Option<SystemTime> {
+    UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+}
+
+/// Converts a Unix timestamp in nanoseconds to a `SystemTime`.
+///
+/// Returns `None` if the timestamp is too far in the future for `SystemTime` to represent.
+pub fn from_unix_nanos(nanos: u64) -> Option<SystemTime> {
+    UNIX_EPOCH.checked_add(Duration::from_nanos(nanos))
+}
+
+/// Converts a `SystemTime` to a `DateTime<Utc>`, or `None` if it is out of chrono's range.
+#[cfg(feature = "chrono")]
+fn to_datetime(time: SystemTime) -> Option<DateTime<Utc>> {
+    let since_epoch = time.duration_since(UNIX_EPOCH).ok()?;
+    DateTime::from_timestamp(
+        i64::try_from(since_epoch.as_secs()).ok()?,
+        since_epoch.subsec_nanos(),
+    )
+}
+
+macro_rules! time_accessors {
+    ($($(#[$doc:meta])* $time:ident, $datetime:ident => |$r:ident| $expr:expr;)*) => {
+        $(
+            $(#[$doc])*
+            ///
+            /// Returns `None` if the timestamp is out of range.
+            pub fn $time(&self) -> Option<SystemTime> {
+                let $r = self;
+                $expr
+            }
+
+            $(#[$doc])*
+            ///
+            /// Returns `None` if the timestamp is out of range.
+            #[cfg(feature = "chrono")]
+            pub fn $datetime(&self) -> Option<DateTime<Utc>> {
+                self.$time().and_then(to_datetime)
+            }
+        )*
+    };
+}
+
+impl Report {
+    time_accessors! {
+        /// Returns `observations_timestamp` (seconds) as a time.
+        observations_time, observations_datetime => |r| from_unix_secs(r.observations_timestamp as u64);
+        /// Returns `valid_from_timestamp` (seconds) as a time.
+        valid_from_time, valid_from_datetime => |r| from_unix_secs(r.valid_from_timestamp as u64);
+    }
+}
+
+macro_rules! impl_common_times {
+    ($($report:ty),* $(,)?) => {
+        $(
+            impl $report {
+                time_accessors! {
+                    /// Returns `observations_timestamp` (seconds) as a time.
+                    observations_time, observations_datetime => |r| from_unix_secs(r.observations_timestamp.into());
+                    /// Returns `valid_from_timestamp` (seconds) as a time.
+                    valid_from_time, valid_from_datetime => |r| from_unix_secs(r.valid_from_timestamp.into());
+                    /// Returns `expires_at` (seconds) as a time.
+                    expires_at_time, expires_at_datetime => |r| from_unix_secs(r.expires_at.into());
+                }
+            }
+        )*
+    };
+}
+
+impl_common_times!(
+    ReportDataV2,
+    ReportDataV3,
+    ReportDataV4,
+    ReportDataV5,
+    ReportDataV6,
+    ReportDataV7,
+    ReportDataV8,
+    ReportDataV9,
+    ReportDataV10,
+    ReportDataV11,
+    ReportDataV12,
+    ReportDataV13,
+);
+
+impl ReportDataV1 {
+    time_accessors! {
+        /// Returns `observations_timestamp` (seconds) as a time.
+        observations_time, observations_datetime => |r| from_unix_secs(r.observations_timestamp.into());
+        /// Returns `current_block_timestamp` (seconds) as a time.
+        current_block_time, current_block_datetime => |r| from_unix_secs(r.current_block_timestamp);
+    }
+}
+
+impl ReportDataV5 {
+    time_accessors! {
+        /// Returns `timestamp` (seconds), when the rate was observed, as a time.
+        rate_time, rate_datetime => |r| from_unix_secs(r.timestamp.into());
+    }
+}
+
+impl ReportDataV8 {
+    time_accessors! {
+        /// Returns `last_update_timestamp` (nanoseconds) as a time.
+        last_update_time, last_update_datetime => |r| from_unix_nanos(r.last_update_timestamp);
+    }
+}
+
+impl ReportDataV9 {
+    time_accessors! {
+        /// Returns `nav_date` (nanoseconds) as a time.
+        nav_time, nav_datetime => |r| from_unix_nanos(r.nav_date);
+    }
+}
+
+impl ReportDataV10 {
+    time_accessors! {
+        /// Returns `last_update_timestamp` (nanoseconds) as a time.
+        last_update_time, last_update_datetime => |r| from_unix_nanos(r.last_update_timestamp);
+        /// Returns `activation_date_time` (seconds) as a time, or `None` if no corporate action is
+        /// scheduled.
+        activation_time, activation_datetime => |r| Some(r.activation_date_time)
+            .filter(|&secs| secs != 0)
+            .and_then(|secs| from_unix_secs(secs.into()));
+    }
+}
+
+impl ReportDataV11 {
+    time_accessors! {
+        /// Returns `last_seen_timestamp_ns` (nanoseconds) as a time.
+        last_seen_time, last_seen_datetime => |r| from_unix_nanos(r.last_seen_timestamp_ns);
+    }
+}
+
+impl ReportDataV12 {
+    time_accessors! {
+        /// Returns `nav_date` (nanoseconds) as a time, or `None` if it is negative.
+        nav_time, nav_datetime => |r| u64::try_from(r.nav_date).ok().and_then(from_unix_nanos);
+    }
+}
+
+impl ReportData {
+    time_accessors! {
+        /// Returns the report's `observations_timestamp` (seconds) as a time. Defined for every schema.
+        observations_time, observations_datetime => |r| from_unix_secs(r.observations_timestamp().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::{
+        generate_mock_report_data_v1, generate_mock_report_data_v10, generate_mock_report_data_v11,
+        generate_mock_report_data_v12, generate_mock_report_data_v3, MOCK_LAST_SEEN_TIMESTAMP_NS,
+        MOCK_TIMESTAMP,
+    };
+
+    #[test]
+    fn test_seconds_and_nanoseconds_agree() {
+        let secs = from_unix_secs(MOCK_TIMESTAMP.into()).unwrap();
+        let nanos = from_unix_nanos(MOCK_TIMESTAMP as u64 * 1_000_000_000).unwrap();
+
+        assert_eq!(secs, nanos);
+        assert_eq!(
+            secs.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            MOCK_TIMESTAMP as u64
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_timestamps() {
+        assert_eq!(from_unix_secs(u64::MAX), None);
+        assert!(from_unix_nanos(u64::MAX).is_some());
+
+        let mut v3 = generate_mock_report_data_v3();
+        v3.expires_at = u32::MAX;
+        assert!(v3.expires_at_time().is_some());
+
+        let mut v1 = generate_mock_report_data_v1();
+        v1.current_block_timestamp = u64::MAX;
+        assert_eq!(v1.current_block_time(), None);
+    }
+
+    #[test]
+    fn test_report_data_times() {
+        let expected = from_unix_secs(MOCK_TIMESTAMP.into());
+
+        let v3 = generate_mock_report_data_v3();
+        assert_eq!(v3.observations_time(), expected);
+        assert_eq!(v3.valid_from_time(), expected);
+        assert_eq!(
+            v3.expires_at_time(),
+            expected.map(|time| time + Duration::from_secs(100))
+        );
+
+        let v1 = generate_mock_report_data_v1();
+        assert_eq!(v1.observations_time(), expected);
+        assert_eq!(v1.current_block_time(), expected);
+        assert_eq!(ReportData::V1(v1).observations_time(), expected);
+
+        let v11 = generate_mock_report_data_v11();
+        assert_eq!(
+            v11.last_seen_time(),
+            from_unix_nanos(MOCK_LAST_SEEN_TIMESTAMP_NS)
+        );
+        assert_eq!(v11.last_seen_time(), expected);
+    }
+
+    #[test]
+    fn test_v12_nav_date_is_nanoseconds() {
+        let mut v12 = generate_mock_report_data_v12();
+        v12.nav_date = MOCK_LAST_SEEN_TIMESTAMP_NS as i64;
+        assert_eq!(v12.nav_time(), from_unix_secs(MOCK_TIMESTAMP.into()));
+
+        v12.nav_date = -1;
+        assert_eq!(v12.nav_time(), None);
+    }
+
+    #[test]
+    fn test_v10_activation_time() {
+        let mut v10 = generate_mock_report_data_v10();
+        v10.activation_date_time = MOCK_TIMESTAMP;
+        assert_eq!(v10.activation_time(), from_unix_secs(MOCK_TIMESTAMP.into()));
+
+        v10.activation_date_time = 0;
+        assert_eq!(v10.activation_time(), None);
+    }
+
+    #[test]
+    fn test_report_times() {
+        let report = Report::builder(ReportData::V3(generate_mock_report_data_v3()))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            report.observations_time(),
+            from_unix_secs(MOCK_TIMESTAMP.into())
+        );
+        assert_eq!(
+            report.valid_from_time(),
+            from_unix_secs(MOCK_TIMESTAMP.into())
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetimes() {
+        let v11 = generate_mock_report_data_v11();
+
+        assert_eq!(
+            v11.observations_datetime().map(|dt| dt.timestamp()),
+            Some(MOCK_TIMESTAMP as i64)
+        );
+        assert_eq!(
+            v11.last_seen_datetime()
+                .and_then(|dt| dt.timestamp_nanos_opt()),
+            Some(MOCK_LAST_SEEN_TIMESTAMP_NS as i64)
+        );
+
+        let mut v1 = generate_mock_report_data_v1();
+        v1.current_block_timestamp = u64::MAX;
+        assert_eq!(v1.current_block_datetime(), None);
+
+        // Representable as a `SystemTime`, but past chrono's range
+        v1.current_block_timestamp = i64::MAX as u64;
+        assert!(v1.current_block_time().is_some());
+        assert_eq!(v1.current_block_datetime(), None);
+    }
+}
@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::{bigint_string, hex_bytes32};
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V1 Schema.
 ///
@@ -30,14 +33,18 @@ use num_bigint::BigInt;
 ///     uint64 currentBlockTimestamp;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV1 {
     pub feed_id: ID,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub benchmark_price: BigInt,
+    #[serde(with = "bigint_string")]
     pub bid: BigInt,
+    #[serde(with = "bigint_string")]
     pub ask: BigInt,
     pub current_block_num: u64,
+    #[serde(with = "hex_bytes32")]
     pub current_block_hash: [u8; 32],
     pub valid_from_block_num: u64,
     pub current_block_timestamp: u64,
@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V10 Schema.
 ///
@@ -14,12 +17,12 @@ use num_bigint::BigInt;
 /// - `native_fee`: Base cost to validate a transaction using the report, denominated in the chain's native token (e.g., WETH/ETH).
 /// - `link_fee`: Base cost to validate a transaction using the report, denominated in LINK.
 /// - `expires_at`: Latest timestamp where the report can be verified onchain.
-/// - `last_update_timestamp`: Timestamp of the last valid price update.
+/// - `last_update_timestamp`: Timestamp of the last valid price update (nanoseconds).
 /// - `price`: DON's consensus price (18 decimal precision).
 /// - `market_status`: Market status - 0 (Unknown), 1 (Closed), 2 (Open).
 /// - `current_multiplier`: Currently applied multiplier accounting for past corporate actions.
 /// - `new_multiplier`: Multiplier to be applied at the `activation_date_time` (set to 0 if none is scheduled).
-/// - `activation_date_time`: When the next corporate action takes effect (set to 0 if none is scheduled).
+/// - `activation_date_time`: When the next corporate action takes effect, in seconds (set to 0 if none is scheduled).
 /// - `tokenized_price`: 24/7 tokenized equity price.
 ///
 /// # Solidity Equivalent
@@ -40,24 +43,33 @@ use num_bigint::BigInt;
 ///     int192 tokenizedPrice;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV10 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
     pub last_update_timestamp: u64,
+    #[serde(with = "bigint_string")]
     pub price: BigInt,
     pub market_status: u32,
+    #[serde(with = "bigint_string")]
     pub current_multiplier: BigInt,
+    #[serde(with = "bigint_string")]
     pub new_multiplier: BigInt,
     pub activation_date_time: u32,
+    #[serde(with = "bigint_string")]
     pub tokenized_price: BigInt,
 }
 
 impl ReportDataV10 {
+    /// Number of decimal places the prices in this schema are carried to.
+    pub const DECIMALS: u32 = 18;
+
     /// Decodes an ABI-encoded `ReportDataV10` from bytes.
     ///
     /// # Parameters
@@ -82,8 +94,8 @@ impl ReportDataV10 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let last_update_timestamp = ReportBase::read_uint64(data, 6 * ReportBase::WORD_SIZE)?;
         let price = ReportBase::read_int192(data, 7 * ReportBase::WORD_SIZE)?;
@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V11 Schema.
 ///
@@ -42,25 +45,36 @@ use num_bigint::BigInt;
 ///     uint32 market_status;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV11 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub mid: BigInt,
     pub last_seen_timestamp_ns: u64,
+    #[serde(with = "bigint_string")]
     pub bid: BigInt,
+    #[serde(with = "bigint_string")]
     pub bid_volume: BigInt,
+    #[serde(with = "bigint_string")]
     pub ask: BigInt,
+    #[serde(with = "bigint_string")]
     pub ask_volume: BigInt,
+    #[serde(with = "bigint_string")]
     pub last_traded_price: BigInt,
     pub market_status: u32,
 }
 
 impl ReportDataV11 {
+    /// Number of decimal places the prices in this schema are carried to.
+    pub const DECIMALS: u32 = 18;
+
     /// Decodes an ABI-encoded `ReportDataV11` from bytes.
     ///
     /// # Parameters
@@ -85,8 +99,8 @@ impl ReportDataV11 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let mid = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
         let last_seen_timestamp_ns = ReportBase::read_uint64(data, 7 * ReportBase::WORD_SIZE)?;
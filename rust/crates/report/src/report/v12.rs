@@ -1,6 +1,9 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V12 Schema.
 ///
@@ -36,15 +39,19 @@ use num_bigint::BigInt;
 ///     uint32 ripcord;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV12 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub nav_per_share: BigInt,
+    #[serde(with = "bigint_string")]
     pub next_nav_per_share: BigInt,
     pub nav_date: i64,
     pub ripcord: u32,
@@ -75,8 +82,8 @@ impl ReportDataV12 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let nav_per_share = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
         let next_nav_per_share = ReportBase::read_int192(data, 7 * ReportBase::WORD_SIZE)?;
@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V13 Schema.
 ///
@@ -36,22 +39,30 @@ use num_bigint::BigInt;
 ///     int192 last_traded_price;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV13 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub best_ask: BigInt,
+    #[serde(with = "bigint_string")]
     pub best_bid: BigInt,
     pub ask_volume: u64,
     pub bid_volume: u64,
+    #[serde(with = "bigint_string")]
     pub last_traded_price: BigInt,
 }
 
 impl ReportDataV13 {
+    /// Number of decimal places the prices in this schema are carried to.
+    pub const DECIMALS: u32 = 18;
+
     /// Decodes an ABI-encoded `ReportDataV13` from bytes.
     ///
     /// # Parameters
@@ -66,7 +77,7 @@ impl ReportDataV13 {
     ///
     /// Returns a `ReportError` if the data is too short or if the data is invalid.
     pub fn decode(data: &[u8]) -> Result<Self, ReportError> {
-        if data.len() < 10 * ReportBase::WORD_SIZE {
+        if data.len() < 11 * ReportBase::WORD_SIZE {
             return Err(ReportError::DataTooShort("ReportDataV13"));
         }
 
@@ -76,8 +87,8 @@ impl ReportDataV13 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let best_ask = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
         let best_bid = ReportBase::read_int192(data, 7 * ReportBase::WORD_SIZE)?;
@@ -110,7 +121,7 @@ impl ReportDataV13 {
     ///
     /// Returns a `ReportError` if the data is invalid.
     pub fn abi_encode(&self) -> Result<Vec<u8>, ReportError> {
-        let mut buffer = Vec::with_capacity(10 * ReportBase::WORD_SIZE);
+        let mut buffer = Vec::with_capacity(11 * ReportBase::WORD_SIZE);
 
         buffer.extend_from_slice(&self.feed_id.0);
         buffer.extend_from_slice(&ReportBase::encode_uint32(self.valid_from_timestamp)?);
@@ -168,4 +179,16 @@ mod tests {
         assert_eq!(decoded.bid_volume, expected_bid_volume);
         assert_eq!(decoded.last_traded_price, expected_last_traded_price);
     }
+
+    #[test]
+    fn test_decode_report_data_v13_too_short() {
+        let encoded = generate_mock_report_data_v13().abi_encode().unwrap();
+        assert_eq!(encoded.len(), 11 * ReportBase::WORD_SIZE);
+
+        // Missing only the last word (last_traded_price)
+        assert!(matches!(
+            ReportDataV13::decode(&encoded[..10 * ReportBase::WORD_SIZE]),
+            Err(ReportError::DataTooShort("ReportDataV13"))
+        ));
+    }
 }
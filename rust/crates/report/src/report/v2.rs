@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V2 Schema.
 ///
@@ -26,18 +29,24 @@ use num_bigint::BigInt;
 ///     int192 benchmarkPrice;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV2 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub benchmark_price: BigInt,
 }
 
 impl ReportDataV2 {
+    /// Number of decimal places the prices in this schema are carried to.
+    pub const DECIMALS: u32 = 8;
+
     /// Decodes an ABI-encoded `ReportDataV2` from bytes.
     ///
     /// # Parameters
@@ -62,8 +71,8 @@ impl ReportDataV2 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let benchmark_price = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
 
@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V3 Schema (Crypto Streams).
 ///
@@ -30,16 +33,21 @@ use num_bigint::BigInt;
 ///         int192 ask;
 ///     }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV3 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub benchmark_price: BigInt,
+    #[serde(with = "bigint_string")]
     pub bid: BigInt,
+    #[serde(with = "bigint_string")]
     pub ask: BigInt,
 }
 
@@ -88,8 +96,8 @@ impl ReportDataV3 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let benchmark_price = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
         let bid = ReportBase::read_int192(data, 7 * ReportBase::WORD_SIZE)?;
@@ -166,4 +174,17 @@ mod tests {
         assert_eq!(decoded.bid, expected_price.clone() - delta.clone());
         assert_eq!(decoded.ask, expected_price + delta);
     }
+
+    #[test]
+    fn test_decode_rejects_fee_with_non_zero_padding() {
+        let mut encoded = generate_mock_report_data_v3().abi_encode().unwrap();
+
+        // First padding byte of the native_fee word
+        encoded[3 * 32] = 0x80;
+
+        assert!(matches!(
+            ReportDataV3::decode(&encoded),
+            Err(ReportError::InvalidValue("uint192"))
+        ));
+    }
 }
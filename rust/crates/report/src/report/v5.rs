@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V5 Schema.
 ///
@@ -13,7 +16,7 @@ use num_bigint::BigInt;
 /// - `link_fee`: Base cost to validate a transaction using the report, denominated in LINK.
 /// - `expires_at`: Latest timestamp where the report can be verified onchain.
 /// - `rate`: The interest rate.
-/// - `timestamp`: Timestamp when the rate was observed.
+/// - `timestamp`: Timestamp when the rate was observed (seconds).
 /// - `duration`: Duration for which the rate is applicable.
 ///
 /// # Solidity Equivalent
@@ -30,14 +33,17 @@ use num_bigint::BigInt;
 ///     uint32 duration;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV5 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub rate: BigInt,
     pub timestamp: u32,
     pub duration: u32,
@@ -68,8 +74,8 @@ impl ReportDataV5 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let rate = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
         let timestamp = ReportBase::read_uint32(data, 7 * ReportBase::WORD_SIZE)?;
@@ -1,7 +1,10 @@
 use crate::feed_id::ID;
 use crate::report::base::{ReportBase, ReportError};
+use crate::report::serde_fields::bigint_string;
 
+use alloc::vec::Vec;
 use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
 
 /// Represents a Report Data V9 Schema (NAV Data Streams).
 ///
@@ -13,7 +16,7 @@ use num_bigint::BigInt;
 /// - `link_fee`: Base cost to validate a transaction using the report, denominated in LINK.
 /// - `expires_at`: Latest timestamp where the report can be verified onchain.
 /// - `nav_per_share`: DON's consensus NAV per share (18 decimal precision).
-/// - `nav_date`: Timestamp for the date the NAV report was produced.
+/// - `nav_date`: Timestamp for the date the NAV report was produced (nanoseconds).
 /// - `aum`: DON's consensus for the total Assets Under Management (18 decimal precision).
 /// - `ripcord`: Emergency pause flag (0 = normal, 1 = paused - do not consume NAV data).
 ///
@@ -37,21 +40,28 @@ use num_bigint::BigInt;
 ///     uint32 ripcord;
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReportDataV9 {
     pub feed_id: ID,
     pub valid_from_timestamp: u32,
     pub observations_timestamp: u32,
+    #[serde(with = "bigint_string")]
     pub native_fee: BigInt,
+    #[serde(with = "bigint_string")]
     pub link_fee: BigInt,
     pub expires_at: u32,
+    #[serde(with = "bigint_string")]
     pub nav_per_share: BigInt,
     pub nav_date: u64,
+    #[serde(with = "bigint_string")]
     pub aum: BigInt,
     pub ripcord: u32,
 }
 
 impl ReportDataV9 {
+    /// Number of decimal places the prices in this schema are carried to.
+    pub const DECIMALS: u32 = 18;
+
     /// Decodes an ABI-encoded `ReportDataV9` from bytes.
     ///
     /// # Parameters
@@ -76,8 +86,8 @@ impl ReportDataV9 {
 
         let valid_from_timestamp = ReportBase::read_uint32(data, ReportBase::WORD_SIZE)?;
         let observations_timestamp = ReportBase::read_uint32(data, 2 * ReportBase::WORD_SIZE)?;
-        let native_fee = ReportBase::read_uint192(data, 3 * ReportBase::WORD_SIZE)?;
-        let link_fee = ReportBase::read_uint192(data, 4 * ReportBase::WORD_SIZE)?;
+        let native_fee = ReportBase::read_uint192_strict(data, 3 * ReportBase::WORD_SIZE)?;
+        let link_fee = ReportBase::read_uint192_strict(data, 4 * ReportBase::WORD_SIZE)?;
         let expires_at = ReportBase::read_uint32(data, 5 * ReportBase::WORD_SIZE)?;
         let nav_per_share = ReportBase::read_int192(data, 6 * ReportBase::WORD_SIZE)?;
         let nav_date = ReportBase::read_uint64(data, 7 * ReportBase::WORD_SIZE)?;
@@ -44,7 +44,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         // Uncomment to print the raw report data
         // println!("Raw Report data: {}", report.full_report);
 
-        let full_report = hex::decode(&report.full_report[2..])?;
+        let full_report = report.full_report_bytes()?;
         let (_report_context, report_blob) = decode_full_report(&full_report)?;
 
         let report_data = ReportDataV3::decode(&report_blob)?;
@@ -56,7 +56,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("Valid From Timestamp: {}", report.valid_from_timestamp);
                 println!("Observations Timestamp: {}", report.observations_timestamp);
 
-                let full_report = hex::decode(&report.full_report[2..])?;
+                let full_report = report.full_report_bytes()?;
                 let (_report_context, report_blob) = decode_full_report(&full_report)?;
 
                 let report_data = ReportDataV3::decode(&report_blob)?;
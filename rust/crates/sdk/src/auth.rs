@@ -3,8 +3,9 @@ use crate::endpoints::{get_authz_header, get_authz_sig_header, get_authz_ts_head
 use hmac::{Hmac, Mac};
 use reqwest::header::{HeaderMap, HeaderValue, InvalidHeaderValue};
 use sha2::{digest::InvalidLength, Digest, Sha256};
-use std::time::SystemTimeError;
+use std::time::{SystemTime, SystemTimeError, UNIX_EPOCH};
 use thiserror::Error;
+use zeroize::{Zeroize, Zeroizing};
 
 /// Type alias for HMAC-SHA256.
 type HmacSha256 = Hmac<Sha256>;
@@ -21,6 +22,46 @@ pub enum HmacError {
     InvalidHeaderValue(#[from] InvalidHeaderValue),
 }
 
+/// Returns the Unix time in milliseconds that requests are signed with: the result of `clock` if
+/// set, the system clock otherwise.
+///
+/// # Errors
+///
+/// Returns `HmacError::SystemTimeError` if the system clock is set before the Unix epoch.
+pub(crate) fn auth_timestamp(clock: Option<fn() -> u128>) -> Result<u128, HmacError> {
+    match clock {
+        Some(clock) => Ok(clock()),
+        None => Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis()),
+    }
+}
+
+/// Returns the HMAC-SHA256 state over the string a request is signed with, ready to finalize or verify.
+fn request_mac(
+    method: &str,
+    path: &str,
+    body: &[u8],
+    client_id: &str,
+    timestamp: u128,
+    user_secret: &str,
+) -> Result<HmacSha256, HmacError> {
+    let mut hasher = Sha256::new();
+    hasher.update(body);
+    let server_body_hash = hasher.finalize();
+    let server_body_hash_hex = hex::encode(server_body_hash);
+
+    // Create the server body hash string. It contains the API key, so it is zeroized on drop
+    let server_body_hash_string = Zeroizing::new(format!(
+        "{} {} {} {} {}",
+        method, path, server_body_hash_hex, client_id, timestamp
+    ));
+
+    // Compute HMAC-SHA256 of the server body hash string
+    let mut mac = HmacSha256::new_from_slice(user_secret.as_bytes())?;
+    mac.update(server_body_hash_string.as_bytes());
+
+    Ok(mac)
+}
+
 /// Generates an HMAC-SHA256 signature based on the provided parameters.
 ///
 /// # Arguments
@@ -34,7 +75,8 @@ pub enum HmacError {
 ///
 /// # Returns
 ///
-/// A `Result` containing the hex-encoded HMAC string if successful, or an error.
+/// A `Result` containing the hex-encoded HMAC string if successful, or an error. The string and
+/// every intermediate buffer holding the signature are zeroized when dropped.
 fn generate_hmac(
     method: &str,
     path: &str,
@@ -42,28 +84,53 @@ fn generate_hmac(
     client_id: &str,
     timestamp: u128,
     user_secret: &str,
-) -> Result<String, HmacError> {
-    let mut hasher = Sha256::new();
-    hasher.update(body);
-    let server_body_hash = hasher.finalize();
-    let server_body_hash_hex = hex::encode(server_body_hash);
-
-    // Create the server body hash string
-    let server_body_hash_string = format!(
-        "{} {} {} {} {}",
-        method, path, server_body_hash_hex, client_id, timestamp
-    );
-
-    // Compute HMAC-SHA256 of the server body hash string
-    let mut mac = HmacSha256::new_from_slice(user_secret.as_bytes())?;
-    mac.update(server_body_hash_string.as_bytes());
+) -> Result<Zeroizing<String>, HmacError> {
+    let mac = request_mac(method, path, body, client_id, timestamp, user_secret)?;
     let signed_message = mac.finalize();
-    let signed_message_bytes = signed_message.into_bytes();
-    let user_hmac = hex::encode(signed_message_bytes);
+    let mut signed_message_bytes = signed_message.into_bytes();
+    let user_hmac = Zeroizing::new(hex::encode(signed_message_bytes));
+    signed_message_bytes.as_mut_slice().zeroize();
 
     Ok(user_hmac)
 }
 
+/// Verifies a hex-encoded HMAC-SHA256 request signature, e.g. the `X-Authorization-Signature-SHA256`
+/// header of an incoming request, against the one the parameters produce.
+///
+/// The signatures are compared in constant time, so the comparison leaks nothing about how much
+/// of `provided_sig` is correct.
+///
+/// # Arguments
+///
+/// * `method` - The HTTP method (e.g., "GET", "POST", etc.).
+/// * `path` - The API endpoint path, including the query string.
+/// * `body` - The request body as a byte slice.
+/// * `client_id` - The client's API key.
+/// * `timestamp` - The request timestamp as an `u128`.
+/// * `user_secret` - The client's API secret.
+/// * `provided_sig` - The hex-encoded signature to verify.
+///
+/// # Returns
+///
+/// `true` if `provided_sig` is the signature of the request, `false` otherwise, including when it
+/// is not valid hex.
+pub fn verify_hmac(
+    method: &str,
+    path: &str,
+    body: &[u8],
+    client_id: &str,
+    timestamp: u128,
+    user_secret: &str,
+    provided_sig: &str,
+) -> bool {
+    let Ok(provided_sig) = hex::decode(provided_sig) else {
+        return false;
+    };
+
+    request_mac(method, path, body, client_id, timestamp, user_secret)
+        .is_ok_and(|mac| mac.verify_slice(&provided_sig).is_ok())
+}
+
 /// Generates and inserts authentication headers into the provided `HeaderMap`.
 ///
 /// # Arguments
@@ -78,6 +145,9 @@ fn generate_hmac(
 /// # Returns
 ///
 /// A `HeaderMap` containing the authentication headers if successful, or an error.
+///
+/// Signing leaves no copies of the signed string or the signature in memory, other than the one in
+/// the returned headers.
 pub(crate) fn generate_auth_headers(
     method: &str,
     path: &str,
@@ -116,7 +186,7 @@ mod tests {
         let want = "e9b2aa1deb13b2abd078353a5e335b2f50307159ad28b433157d2c74dbab2072";
         let got = generate_hmac(method, path, body, client_id, timestamp, user_secret).unwrap();
 
-        assert_eq!(got, want);
+        assert_eq!(*got, want);
     }
 
     #[test]
@@ -131,7 +201,7 @@ mod tests {
         let want = "31b48ebdb13802b58978cd89eca0c3c68ddccf85392e703b55942544e7203d3d";
         let got = generate_hmac(method, path, body, client_id, timestamp, user_secret).unwrap();
 
-        assert_eq!(got, want);
+        assert_eq!(*got, want);
     }
 
     #[test]
@@ -146,7 +216,61 @@ mod tests {
         let want = "37190febe20b6f3662f6abbfa3a7085ad705ac64e88bde8c1a01a635859e6cf7";
         let got = generate_hmac(method, path, body, client_id, timestamp, user_secret).unwrap();
 
-        assert_eq!(got, want);
+        assert_eq!(*got, want);
+    }
+
+    #[test]
+    fn auth_timestamp_uses_clock() {
+        assert_eq!(
+            auth_timestamp(Some(|| 1718885772000)).unwrap(),
+            1718885772000
+        );
+        assert!(auth_timestamp(None).unwrap() > 1718885772000);
+    }
+
+    #[test]
+    fn verify_hmac_valid() {
+        let method = "POST";
+        let path = API_V1_REPORTS_BULK;
+        let body = b"{\"attr1\": \"value1\",\"attr2\": [1,2,3]}";
+        let client_id = "clientId2";
+        let user_secret = "secret2";
+        let timestamp = 1718885772;
+        let sig = "37190febe20b6f3662f6abbfa3a7085ad705ac64e88bde8c1a01a635859e6cf7";
+
+        assert!(verify_hmac(
+            method,
+            path,
+            body,
+            client_id,
+            timestamp,
+            user_secret,
+            sig
+        ));
+    }
+
+    #[test]
+    fn verify_hmac_invalid() {
+        let method = "POST";
+        let path = API_V1_REPORTS_BULK;
+        let body = b"{\"attr1\": \"value1\",\"attr2\": [1,2,3]}";
+        let client_id = "clientId2";
+        let user_secret = "secret2";
+        let timestamp = 1718885772;
+        let sig = "37190febe20b6f3662f6abbfa3a7085ad705ac64e88bde8c1a01a635859e6cf7";
+
+        let verify = |timestamp, sig| {
+            verify_hmac(method, path, body, client_id, timestamp, user_secret, sig)
+        };
+
+        // Tampered, truncated and non-hex signatures, and a different timestamp
+        assert!(!verify(
+            timestamp,
+            "47190febe20b6f3662f6abbfa3a7085ad705ac64e88bde8c1a01a635859e6cf7"
+        ));
+        assert!(!verify(timestamp, &sig[..32]));
+        assert!(!verify(timestamp, "not a signature"));
+        assert!(!verify(timestamp + 1, sig));
     }
 
     #[test]
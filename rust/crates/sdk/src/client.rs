@@ -1,24 +1,41 @@
-use crate::auth::{generate_auth_headers, HmacError};
+use crate::auth::{auth_timestamp, generate_auth_headers, HmacError};
 use crate::config::Config;
 use crate::endpoints::{
     API_V1_FEEDS, API_V1_REPORTS, API_V1_REPORTS_BULK, API_V1_REPORTS_LATEST, API_V1_REPORTS_PAGE,
 };
 use crate::feed::Feed;
+use crate::page_stream::reports_stream;
+
+use async_trait::async_trait;
+use futures::{stream, StreamExt};
 
 use chainlink_data_streams_report::feed_id::ID;
-use chainlink_data_streams_report::report::Report;
+use chainlink_data_streams_report::report::base::ReportError;
+use chainlink_data_streams_report::report::{decode_full_report_strict, Report};
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
 
-use reqwest::Client as HttpClient;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Client as HttpClient, Proxy, RequestBuilder, Response, StatusCode,
+};
 use serde::Deserialize;
 use serde_urlencoded;
-use std::time::{SystemTime, UNIX_EPOCH};
+use sha3::{Digest, Keccak256};
+use std::collections::HashSet;
+use std::future::Future;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 
 /// Errors that can occur within the client.
 #[derive(Error, Debug)]
 pub enum ClientError {
     #[error("HTTP request failed: {0}")]
-    HttpRequestError(#[from] reqwest::Error),
+    HttpRequestError(reqwest::Error),
+
+    #[error("HTTP request timed out: {0}")]
+    Timeout(reqwest::Error),
 
     #[error("HMAC generation failed: {0}")]
     HmacError(#[from] HmacError),
@@ -26,25 +43,149 @@ pub enum ClientError {
     #[error("Invalid response format: {0}")]
     InvalidResponseFormat(#[from] serde_json::Error),
 
-    #[error("API error: {0}")]
-    ApiError(String),
+    #[error("API error ({status}): {body}")]
+    ApiError { status: u16, body: String },
+
+    #[error("Rate limited by the API (retry after {retry_after:?})")]
+    RateLimited { retry_after: Option<Duration> },
+
+    #[error("Pagination stalled: the server kept returning the same page for startTimestamp {start_timestamp}")]
+    PaginationStalled { start_timestamp: u128 },
+
+    #[error("Invalid report: {0}")]
+    InvalidReport(#[from] ReportError),
+
+    #[error("Invalid signature {index}: {reason}")]
+    InvalidSignature { index: usize, reason: String },
+
+    #[error("Request cancelled")]
+    Cancelled,
+}
+
+impl ClientError {
+    /// Returns `true` if the failed request is worth retrying.
+    ///
+    /// Transport failures, timeouts, `429 Too Many Requests` and `5xx` responses are considered transient.
+    /// Other `4xx` responses (e.g. `400`, `401`), HMAC failures and undecodable responses are not,
+    /// since repeating the same request would fail the same way.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ClientError::HttpRequestError(e) => match e.status() {
+                Some(status) => is_retryable_status(status.as_u16()),
+                None => e.is_timeout() || e.is_connect() || e.is_request() || e.is_body(),
+            },
+            ClientError::Timeout(_) | ClientError::RateLimited { .. } => true,
+            ClientError::ApiError { status, .. } => is_retryable_status(*status),
+            ClientError::HmacError(_)
+            | ClientError::InvalidResponseFormat(_)
+            | ClientError::PaginationStalled { .. }
+            | ClientError::InvalidReport(_)
+            | ClientError::InvalidSignature { .. }
+            | ClientError::Cancelled => false,
+        }
+    }
+}
+
+/// Timeouts are kept apart from other transport failures, so callers can tell them apart.
+impl From<reqwest::Error> for ClientError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            ClientError::Timeout(e)
+        } else {
+            ClientError::HttpRequestError(e)
+        }
+    }
+}
+
+/// Page size used by `Client::get_reports_page_all`, and the minimum used by
+/// `Client::get_reports_page_desc`.
+const PAGE_ALL_LIMIT: usize = 100;
+
+/// Number of requests `Client::get_latest_reports_bulk` runs at once.
+const LATEST_BULK_CONCURRENCY: usize = 10;
+
+/// Number of pages in a row that may fail to advance the cursor before
+/// `Client::get_reports_page_all` gives up.
+const MAX_STALLED_PAGES: usize = 3;
+
+fn is_retryable_status(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+/// Turns a `4xx` or `5xx` response into a `ClientError::ApiError` carrying its status and body,
+/// which usually explains what was wrong with the request.
+/// A `429 Too Many Requests` response becomes a `ClientError::RateLimited` instead.
+async fn error_for_status(response: Response) -> Result<Response, ClientError> {
+    let status = response.status();
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+
+        return Err(ClientError::RateLimited { retry_after });
+    }
+
+    if status.is_client_error() || status.is_server_error() {
+        let body = response.text().await?;
+
+        return Err(ClientError::ApiError {
+            status: status.as_u16(),
+            body,
+        });
+    }
+
+    Ok(response)
+}
+
+/// Parses a `Retry-After` header value, either a number of seconds or an HTTP date.
+/// A date in the past means the request can be retried right away.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
 }
 
+/// Response body of `/api/v1/feeds`.
 #[derive(Debug, Deserialize)]
-struct FeedsResponse {
-    feeds: Vec<Feed>,
+pub struct FeedsResponse {
+    pub feeds: Vec<Feed>,
 }
 
+/// Response body of `/api/v1/reports/latest` and `/api/v1/reports`.
 #[derive(Debug, Deserialize)]
 pub struct ReportResponse {
     pub report: Report,
 }
 
+/// Response body of `/api/v1/reports/bulk` and `/api/v1/reports/page`.
 #[derive(Debug, Deserialize)]
-struct ReportsResponse {
-    reports: Vec<Report>,
+pub struct ReportsResponse {
+    pub reports: Vec<Report>,
 }
 
+/// Reports returned by `Client::get_reports_bulk_with_missing`.
+#[derive(Debug)]
+pub struct BulkReportsResult {
+    /// Reports that were available at the requested timestamp
+    pub reports: Vec<Report>,
+    /// Requested feed IDs without a report, in request order
+    pub missing_feed_ids: Vec<ID>,
+    /// Whether the server answered any request with `206 Partial Content`
+    pub partial_content: bool,
+}
+
+#[derive(Clone)]
 pub struct Client {
     config: Config,
     http: HttpClient,
@@ -61,13 +202,70 @@ impl Client {
     ///
     /// Returns an error if the HTTP client fails to initialize.
     pub fn new(config: Config) -> Result<Self, ClientError> {
-        let http = HttpClient::builder()
+        let mut http = HttpClient::builder()
             .danger_accept_invalid_certs(config.insecure_skip_verify.to_bool())
-            .build()?;
+            .tcp_keepalive(config.tcp_keepalive)
+            .gzip(config.http_compression)
+            .deflate(config.http_compression);
+
+        if let Some(http_timeout) = config.http_timeout {
+            http = http.timeout(http_timeout);
+        }
+
+        if let Some(proxy) = &config.proxy {
+            http = http.proxy(Proxy::all(proxy)?);
+        }
+
+        let http = http.build()?;
 
         Ok(Client { config, http })
     }
 
+    /// Creates a new `Client` instance that sends requests with an existing `reqwest::Client`.
+    ///
+    /// Clients sharing a `reqwest::Client` share its connection pool, e.g. one `Client` per API key
+    /// in the same service. Cloning a `Client` shares the pool too.
+    ///
+    /// The HTTP settings of `config` (`insecure_skip_verify`, `tcp_keepalive`, `http_compression`,
+    /// `http_timeout` and `proxy`) are applied when building a `reqwest::Client`, so they have no
+    /// effect here: configure `http` with them instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - A validated `Config` instance.
+    /// * `http` - The `reqwest::Client` to send requests with.
+    pub fn with_http_client(config: Config, http: HttpClient) -> Self {
+        Client { config, http }
+    }
+
+    /// Returns the API key and secret that sign requests for `feed_id`.
+    fn credentials_for(&self, feed_id: &ID) -> (&str, &str) {
+        match self.config.feed_credentials.get(&feed_id.version()) {
+            Some(credentials) => (&credentials.api_key, &credentials.api_secret),
+            None => (&self.config.api_key, &self.config.api_secret),
+        }
+    }
+
+    /// Returns the configured extra headers merged with a request's authentication headers,
+    /// which take precedence.
+    fn request_headers(&self, auth_headers: HeaderMap) -> HeaderMap {
+        let mut headers = self.config.extra_headers.clone();
+        headers.extend(auth_headers);
+        headers
+    }
+
+    /// Builds and sends a request, passing it to the configured `inspect_http_request` first.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        let request = request.build()?;
+
+        // Optionally inspect the request
+        if let Some(inspect_fn) = &self.config.inspect_http_request {
+            inspect_fn(&request);
+        }
+
+        Ok(self.http.execute(request).await?)
+    }
+
     /// Returns a list of available feeds.
     ///
     /// # Endpoint:
@@ -98,22 +296,14 @@ impl Client {
         let body = b"";
         let client_id = &self.config.api_key;
         let user_secret = &self.config.api_secret;
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error: Timestamp in the past")
-            .as_millis();
+        let timestamp = auth_timestamp(self.config.auth_clock)?;
 
         let headers = generate_auth_headers(method, path, body, client_id, user_secret, timestamp)?;
 
         // Make the GET request
-        let response = self
-            .http
-            .get(url)
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()
-            .map_err(|e| ClientError::ApiError(e.to_string()))?;
+        let request = self.http.get(url).headers(self.request_headers(headers));
+        let response = self.send(request).await?;
+        let response = error_for_status(response).await?;
 
         // Optionally inspect the response
         if let Some(inspect_fn) = &self.config.inspect_http_response {
@@ -125,6 +315,22 @@ impl Client {
         Ok(feeds_response.feeds)
     }
 
+    /// Returns the available feeds whose report schema is `version`.
+    ///
+    /// The API has no server-side version filter, so this fetches every feed with
+    /// [`get_feeds`](Client::get_feeds) and filters the result client-side.
+    ///
+    /// # Parameters:
+    /// * `version` - The report schema version, e.g. `3` for crypto feeds.
+    pub async fn get_feeds_by_version(&self, version: u16) -> Result<Vec<Feed>, ClientError> {
+        let feeds = self.get_feeds().await?;
+
+        Ok(feeds
+            .into_iter()
+            .filter(|feed| feed.version().0 == version)
+            .collect())
+    }
+
     /// Returns a single report with the latest timestamp.
     ///
     /// # Endpoint:
@@ -165,31 +371,25 @@ impl Client {
     pub async fn get_latest_report(&self, feed_id: ID) -> Result<ReportResponse, ClientError> {
         let url = format!("{}{}", self.config.rest_url, API_V1_REPORTS_LATEST);
 
+        let (client_id, user_secret) = self.credentials_for(&feed_id);
         let feed_id = feed_id.to_hex_string();
 
         let method = "GET";
         let path = format!("{}?feedID={}", API_V1_REPORTS_LATEST, feed_id);
         let body = b"";
-        let client_id = &self.config.api_key;
-        let user_secret = &self.config.api_secret;
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error: Timestamp in the past")
-            .as_millis();
+        let timestamp = auth_timestamp(self.config.auth_clock)?;
 
         let headers =
             generate_auth_headers(method, &path, body, client_id, user_secret, timestamp)?;
 
         // Make the GET request
-        let response = self
+        let request = self
             .http
             .get(url)
             .query(&[("feedID", feed_id)])
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()
-            .map_err(|e| ClientError::ApiError(e.to_string()))?;
+            .headers(self.request_headers(headers));
+        let response = self.send(request).await?;
+        let response = error_for_status(response).await?;
 
         // Optionally inspect the response
         if let Some(inspect_fn) = &self.config.inspect_http_response {
@@ -201,6 +401,26 @@ impl Client {
         Ok(report_response)
     }
 
+    /// Returns the latest report of each of `feed_ids`.
+    ///
+    /// The API has no bulk endpoint for latest reports, so this sends a `get_latest_report`
+    /// request per feed, a few at a time. Each feed gets its own result, in the order of
+    /// `feed_ids`, so a feed that fails doesn't fail the others.
+    pub async fn get_latest_reports_bulk(
+        &self,
+        feed_ids: &[ID],
+    ) -> Vec<Result<Report, ClientError>> {
+        stream::iter(feed_ids)
+            .map(|feed_id| async move {
+                self.get_latest_report(*feed_id)
+                    .await
+                    .map(|response| response.report)
+            })
+            .buffered(LATEST_BULK_CONCURRENCY)
+            .collect()
+            .await
+    }
+
     /// Returns a single report at a given timestamp.
     ///
     /// # Endpoint:
@@ -246,6 +466,7 @@ impl Client {
     ) -> Result<ReportResponse, ClientError> {
         let url = format!("{}{}", self.config.rest_url, API_V1_REPORTS);
 
+        let (client_id, user_secret) = self.credentials_for(&feed_id);
         let feed_id = feed_id.to_hex_string();
 
         let method = "GET";
@@ -254,12 +475,7 @@ impl Client {
             API_V1_REPORTS, feed_id, timestamp
         );
         let body = b"";
-        let client_id = &self.config.api_key;
-        let user_secret = &self.config.api_secret;
-        let request_timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error: Timestamp in the past")
-            .as_millis();
+        let request_timestamp = auth_timestamp(self.config.auth_clock)?;
 
         let headers = generate_auth_headers(
             method,
@@ -271,15 +487,13 @@ impl Client {
         )?;
 
         // Make the GET request
-        let response = self
+        let request = self
             .http
             .get(url)
             .query(&[("feedID", feed_id), ("timestamp", timestamp.to_string())])
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()
-            .map_err(|e| ClientError::ApiError(e.to_string()))?;
+            .headers(self.request_headers(headers));
+        let response = self.send(request).await?;
+        let response = error_for_status(response).await?;
 
         // Optionally inspect the response
         if let Some(inspect_fn) = &self.config.inspect_http_response {
@@ -291,6 +505,35 @@ impl Client {
         Ok(report_response)
     }
 
+    /// Returns the report of `feed_id` at each of `timestamps`, sending a `get_report` request per
+    /// timestamp with at most `max_concurrent` in flight at once, to stay clear of rate limits.
+    ///
+    /// Each timestamp gets its own result, in the order of `timestamps`, so a timestamp that fails
+    /// doesn't fail the others. A `max_concurrent` of 0 is treated as 1.
+    pub async fn get_reports_at_timestamps(
+        &self,
+        feed_id: ID,
+        timestamps: &[u128],
+        max_concurrent: usize,
+    ) -> Vec<Result<Report, ClientError>> {
+        let mut indexed: Vec<(usize, Result<Report, ClientError>)> =
+            stream::iter(timestamps.iter().enumerate())
+                .map(|(index, timestamp)| async move {
+                    let result = self
+                        .get_report(feed_id, *timestamp)
+                        .await
+                        .map(|response| response.report);
+                    (index, result)
+                })
+                .buffer_unordered(max_concurrent.max(1))
+                .collect()
+                .await;
+
+        // Requests complete in any order, restore the order of `timestamps`
+        indexed.sort_unstable_by_key(|(index, _)| *index);
+        indexed.into_iter().map(|(_, result)| result).collect()
+    }
+
     /// Returns a report for multiple FeedIDs at a given timestamp.
     ///
     /// # Endpoint:
@@ -302,6 +545,9 @@ impl Client {
     ///
     /// # Parameters:
     /// * `feedIDs` - A comma-separated list of Data Streams feed IDs.
+    ///   Duplicate feed IDs are sent only once, keeping the order in which they first appear.
+    ///   Feeds signed with different credentials (see `ConfigBuilder::with_feed_credentials`) are
+    ///   requested separately, one request per credential set.
     /// * `timestamp` - The Unix timestamp for the reports (in seconds).
     ///
     /// # Sample request:
@@ -331,15 +577,79 @@ impl Client {
     /// | **400 Bad Request** | This error is triggered when:<br>- There is any missing/malformed query argument.<br>- Required headers are missing or provided with incorrect values. |
     /// | **401 Unauthorized User** | This error is triggered when:<br>- Authentication fails, typically because the HMAC signature provided by the client doesn't match the one expected by the server.<br>- A user requests access to a feed without the appropriate permission or that does not exist. |
     /// | **500 Internal Server** | Indicates an unexpected condition encountered by the server, preventing it from fulfilling the request. This error typically points to issues on the server side. |
-    /// | **206 Missing Data** | Indicates that at least one feed ID data is missing from the report. E.g., you requested a report for feed IDs `<feedID1>`, `<feedID2>`, and `<feedID3>` at a given timestamp. If data for `<feedID2>` is missing from the report (not available yet at the specified timestamp), you get `[<feedID1 data>, <feedID3 data>]` and a 206 response. |
+    /// | **206 Missing Data** | Indicates that at least one feed ID data is missing from the report. E.g., you requested a report for feed IDs `<feedID1>`, `<feedID2>`, and `<feedID3>` at a given timestamp. If data for `<feedID2>` is missing from the report (not available yet at the specified timestamp), you get `[<feedID1 data>, <feedID3 data>]` and a 206 response. Use `get_reports_bulk_with_missing` to find out which feeds are missing. |
     pub async fn get_reports_bulk(
         &self,
         feed_ids: &[ID],
         timestamp: u128,
     ) -> Result<Vec<Report>, ClientError> {
+        Ok(self
+            .get_reports_bulk_with_missing(feed_ids, timestamp)
+            .await?
+            .reports)
+    }
+
+    /// Like `get_reports_bulk`, but also reports which of the requested feeds had no report.
+    ///
+    /// The server answers `206 Partial Content` when data for some feeds is not available yet at
+    /// `timestamp`, returning only the reports it has. The feeds left out are found by diffing
+    /// the requested feed IDs against the returned ones, so `missing_feed_ids` is accurate even
+    /// if the server omits a feed without answering `206`.
+    pub async fn get_reports_bulk_with_missing(
+        &self,
+        feed_ids: &[ID],
+        timestamp: u128,
+    ) -> Result<BulkReportsResult, ClientError> {
+        // One request per credential set, each covering the feeds it signs for
+        let mut seen = HashSet::new();
+        let mut unique_feed_ids = Vec::new();
+        let mut requests: Vec<((&str, &str), Vec<String>)> = Vec::new();
+
+        for feed_id in feed_ids.iter().filter(|id| seen.insert(**id)) {
+            unique_feed_ids.push(*feed_id);
+
+            let credentials = self.credentials_for(feed_id);
+
+            match requests.iter_mut().find(|(c, _)| *c == credentials) {
+                Some((_, ids)) => ids.push(feed_id.to_hex_string()),
+                None => requests.push((credentials, vec![feed_id.to_hex_string()])),
+            }
+        }
+
+        let mut reports = Vec::new();
+        let mut partial_content = false;
+
+        for ((client_id, user_secret), feed_ids) in requests {
+            let (signed_reports, partial) = self
+                .get_reports_bulk_signed(&feed_ids, timestamp, client_id, user_secret)
+                .await?;
+
+            reports.extend(signed_reports);
+            partial_content |= partial;
+        }
+
+        let returned: HashSet<ID> = reports.iter().map(|report| report.feed_id).collect();
+        let missing_feed_ids = unique_feed_ids
+            .into_iter()
+            .filter(|feed_id| !returned.contains(feed_id))
+            .collect();
+
+        Ok(BulkReportsResult {
+            reports,
+            missing_feed_ids,
+            partial_content,
+        })
+    }
+
+    async fn get_reports_bulk_signed(
+        &self,
+        feed_ids: &[String],
+        timestamp: u128,
+        client_id: &str,
+        user_secret: &str,
+    ) -> Result<(Vec<Report>, bool), ClientError> {
         let url = format!("{}{}", self.config.rest_url, API_V1_REPORTS_BULK);
 
-        let feed_ids: Vec<String> = feed_ids.iter().map(|id| id.to_hex_string()).collect();
         let feed_ids_joined = feed_ids.join(",");
 
         let timestamp_str = timestamp.to_string();
@@ -354,12 +664,7 @@ impl Client {
         let method = "GET";
         let path = format!("{}?{}", API_V1_REPORTS_BULK, query_string);
         let body = b"";
-        let client_id = &self.config.api_key;
-        let user_secret = &self.config.api_secret;
-        let request_timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error: Timestamp in the past")
-            .as_millis();
+        let request_timestamp = auth_timestamp(self.config.auth_clock)?;
 
         let headers = generate_auth_headers(
             method,
@@ -371,15 +676,14 @@ impl Client {
         )?;
 
         // Make the GET request
-        let response = self
+        let request = self
             .http
             .get(url)
             .query(query_params)
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()
-            .map_err(|e| ClientError::ApiError(e.to_string()))?;
+            .headers(self.request_headers(headers));
+        let response = self.send(request).await?;
+        let response = error_for_status(response).await?;
+        let partial_content = response.status() == StatusCode::PARTIAL_CONTENT;
 
         // Optionally inspect the response
         if let Some(inspect_fn) = &self.config.inspect_http_response {
@@ -390,7 +694,7 @@ impl Client {
 
         let reports = reports_response.reports;
 
-        Ok(reports)
+        Ok((reports, partial_content))
     }
 
     /// Returns multiple sequential reports for a single FeedID, starting at a given timestamp
@@ -441,6 +745,7 @@ impl Client {
     ) -> Result<Vec<Report>, ClientError> {
         let url = format!("{}{}", self.config.rest_url, API_V1_REPORTS_PAGE);
 
+        let (client_id, user_secret) = self.credentials_for(&feed_id);
         let feed_id = feed_id.to_hex_string();
 
         let method = "GET";
@@ -449,12 +754,7 @@ impl Client {
             API_V1_REPORTS_PAGE, feed_id, start_timestamp
         );
         let body = b"";
-        let client_id = &self.config.api_key;
-        let user_secret = &self.config.api_secret;
-        let request_timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error: Timestamp in the past")
-            .as_millis();
+        let request_timestamp = auth_timestamp(self.config.auth_clock)?;
 
         let headers = generate_auth_headers(
             method,
@@ -466,18 +766,16 @@ impl Client {
         )?;
 
         // Make the GET request
-        let response = self
+        let request = self
             .http
             .get(url)
             .query(&[
                 ("feedID", feed_id),
                 ("startTimestamp", start_timestamp.to_string()),
             ])
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()
-            .map_err(|e| ClientError::ApiError(e.to_string()))?;
+            .headers(self.request_headers(headers));
+        let response = self.send(request).await?;
+        let response = error_for_status(response).await?;
 
         // Optionally inspect the response
         if let Some(inspect_fn) = &self.config.inspect_http_response {
@@ -539,8 +837,68 @@ impl Client {
         start_timestamp: u128,
         limit: usize,
     ) -> Result<Vec<Report>, ClientError> {
+        let response = self
+            .reports_page_response(feed_id, start_timestamp, limit)
+            .await?;
+
+        let reports_response = response.json::<ReportsResponse>().await?;
+
+        let reports = reports_response.reports;
+
+        Ok(reports)
+    }
+
+    /// Like `get_reports_page_with_limit`, but yields each report as soon as it has been read
+    /// from the response body, instead of buffering and deserializing the whole page first.
+    ///
+    /// Peak memory is bounded by the largest single report rather than the page, which matters
+    /// for large historical pulls.
+    ///
+    /// # Errors
+    ///
+    /// Errors sending the request or from the response status are returned before any report.
+    /// Errors reading or parsing the body are yielded by the stream, which then ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use chainlink_data_streams_report::feed_id::ID;
+    /// # use chainlink_data_streams_sdk::client::{Client, ClientError};
+    /// # use futures::StreamExt;
+    /// # async fn run(client: Client, feed_id: ID) -> Result<(), ClientError> {
+    /// let reports = client.get_reports_page_stream(feed_id, 1_718_885_772, 1_000).await?;
+    /// futures::pin_mut!(reports);
+    ///
+    /// while let Some(report) = reports.next().await {
+    ///     println!("{}", report?.observations_timestamp);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_reports_page_stream(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+        limit: usize,
+    ) -> Result<impl futures::Stream<Item = Result<Report, ClientError>>, ClientError> {
+        let response = self
+            .reports_page_response(feed_id, start_timestamp, limit)
+            .await?;
+
+        Ok(reports_stream(response.bytes_stream()))
+    }
+
+    /// Sends a `/api/v1/reports/page` request with a `limit`, returning the successful response
+    /// with its body still unread.
+    async fn reports_page_response(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+        limit: usize,
+    ) -> Result<Response, ClientError> {
         let url = format!("{}{}", self.config.rest_url, API_V1_REPORTS_PAGE);
 
+        let (client_id, user_secret) = self.credentials_for(&feed_id);
         let feed_id = feed_id.to_hex_string();
 
         let method = "GET";
@@ -549,12 +907,7 @@ impl Client {
             API_V1_REPORTS_PAGE, feed_id, start_timestamp, limit
         );
         let body = b"";
-        let client_id = &self.config.api_key;
-        let user_secret = &self.config.api_secret;
-        let request_timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error: Timestamp in the past")
-            .as_millis();
+        let request_timestamp = auth_timestamp(self.config.auth_clock)?;
 
         let headers = generate_auth_headers(
             method,
@@ -566,7 +919,7 @@ impl Client {
         )?;
 
         // Make the GET request
-        let response = self
+        let request = self
             .http
             .get(url)
             .query(&[
@@ -574,21 +927,673 @@ impl Client {
                 ("startTimestamp", start_timestamp.to_string()),
                 ("limit", limit.to_string()),
             ])
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()
-            .map_err(|e| ClientError::ApiError(e.to_string()))?;
+            .headers(self.request_headers(headers));
+        let response = self.send(request).await?;
+        let response = error_for_status(response).await?;
 
         // Optionally inspect the response
         if let Some(inspect_fn) = &self.config.inspect_http_response {
             inspect_fn(&response);
         }
 
-        let reports_response = response.json::<ReportsResponse>().await?;
+        Ok(response)
+    }
 
-        let reports = reports_response.reports;
+    /// Returns up to `limit` of the most recent reports for a single FeedID with an
+    /// observations timestamp at or before `end_timestamp`, in descending order.
+    ///
+    /// The server only paginates forward, so this pages through
+    /// `[end_timestamp - limit + 1, end_timestamp]` up to `end_timestamp` and keeps the newest
+    /// `limit` reports. Feeds publishing several reports per second fill that window with more
+    /// than `limit` reports, which takes further pages. For feeds updating less often, the window
+    /// is widened further back, doubling its length each time, until `limit` reports are found or
+    /// it spans `Config::page_desc_max_lookback`; only then are fewer than `limit` reports
+    /// returned. To page further back, call it again with `end_timestamp` set to one less than
+    /// the oldest timestamp returned.
+    ///
+    /// # Parameters:
+    /// * `feed_id` - A Data Streams feed ID.
+    /// * `end_timestamp` - The UNIX timestamp of the newest report to include (in seconds).
+    /// * `limit` - The maximum number of reports to return
+    pub async fn get_reports_page_desc(
+        &self,
+        feed_id: ID,
+        end_timestamp: u128,
+        limit: usize,
+    ) -> Result<Vec<Report>, ClientError> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let oldest_timestamp =
+            end_timestamp.saturating_sub(self.config.page_desc_max_lookback.as_secs() as u128);
+        let mut lookback = limit as u128;
+        let mut window_end = end_timestamp;
+        let mut reports = Vec::new();
+
+        // Each window covers the seconds just before the previous one, so no report is fetched twice
+        loop {
+            let window_start = end_timestamp
+                .saturating_sub(lookback - 1)
+                .max(oldest_timestamp);
+
+            reports.extend(
+                self.get_reports_page_range(
+                    feed_id,
+                    window_start,
+                    window_end,
+                    limit.max(PAGE_ALL_LIMIT),
+                )
+                .await?,
+            );
+
+            if reports.len() >= limit || window_start <= oldest_timestamp {
+                break;
+            }
+
+            window_end = window_start - 1;
+            lookback = lookback.saturating_mul(2);
+        }
+
+        reports.sort_by_key(|report| report.observations_timestamp);
+        let mut reports = reports.split_off(reports.len().saturating_sub(limit));
+        reports.reverse();
 
         Ok(reports)
     }
+
+    /// Returns every report for a single FeedID with an observations timestamp between
+    /// `start_timestamp` and `end_timestamp` (inclusive, in seconds), in ascending order.
+    ///
+    /// Issues successive `/api/v1/reports/page` requests, each starting at the second of the
+    /// newest report received so far, until a page comes back short or passes `end_timestamp`.
+    /// Reports of that second already received are skipped, so a page ending partway through a
+    /// second loses none of it.
+    ///
+    /// # Parameters:
+    /// * `feed_id` - A Data Streams feed ID.
+    /// * `start_timestamp` - The UNIX timestamp of the oldest report to include (in seconds).
+    /// * `end_timestamp` - The UNIX timestamp of the newest report to include (in seconds).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::PaginationStalled` if the server keeps returning pages without new
+    /// reports, instead of requesting the same page forever. This includes a single second
+    /// holding more reports than fit in a page.
+    /// Any error from an individual page request is returned as is.
+    pub async fn get_reports_page_all(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+        end_timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError> {
+        self.get_reports_page_range(feed_id, start_timestamp, end_timestamp, PAGE_ALL_LIMIT)
+            .await
+    }
+
+    /// Pages through `/api/v1/reports/page`, `page_limit` reports at a time, collecting every
+    /// report between `start_timestamp` and `end_timestamp` in ascending order.
+    async fn get_reports_page_range(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+        end_timestamp: u128,
+        page_limit: usize,
+    ) -> Result<Vec<Report>, ClientError> {
+        let mut reports: Vec<Report> = Vec::new();
+        let mut cursor = start_timestamp;
+        // Reports already collected in the cursor's second, which the next page starts with again
+        let mut seen_at_cursor = HashSet::new();
+        let mut stalled_pages = 0;
+
+        while cursor <= end_timestamp {
+            let page = self
+                .get_reports_page_with_limit(feed_id, cursor, page_limit)
+                .await?;
+
+            let page_len = page.len();
+            let newest = page
+                .iter()
+                .map(|report| report.observations_timestamp as u128)
+                .max();
+
+            let collected = reports.len();
+            reports.extend(page.into_iter().filter(|report| {
+                let timestamp = report.observations_timestamp as u128;
+                timestamp >= cursor
+                    && timestamp <= end_timestamp
+                    && !(timestamp == cursor && seen_at_cursor.contains(&report.full_report))
+            }));
+
+            let newest = match newest {
+                Some(newest) => newest,
+                None => break,
+            };
+
+            if page_len < page_limit || newest > end_timestamp {
+                break;
+            }
+
+            // No new reports: either the server ignores the cursor, or a single second holds
+            // more reports than fit in a page, which the cursor can't page within
+            if reports.len() == collected {
+                stalled_pages += 1;
+                if stalled_pages >= MAX_STALLED_PAGES {
+                    return Err(ClientError::PaginationStalled {
+                        start_timestamp: cursor,
+                    });
+                }
+                continue;
+            }
+
+            // A full page may end partway through its newest second, so resume from that second
+            // rather than past it, skipping the reports of it already collected
+            stalled_pages = 0;
+            cursor = newest;
+            seen_at_cursor = reports
+                .iter()
+                .rev()
+                .take_while(|report| report.observations_timestamp as u128 == newest)
+                .map(|report| report.full_report.clone())
+                .collect();
+        }
+
+        Ok(reports)
+    }
+
+    /// Like `get_reports_page_all`, but returns `ClientError::Cancelled` as soon as `cancel` is
+    /// cancelled, aborting the page request in flight. The reports fetched so far are discarded.
+    pub async fn get_reports_page_all_with_cancellation(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+        end_timestamp: u128,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<Report>, ClientError> {
+        with_cancellation(
+            cancel,
+            self.get_reports_page_all(feed_id, start_timestamp, end_timestamp),
+        )
+        .await
+    }
+}
+
+/// The report queries of [`Client`], as a trait.
+///
+/// Code written against `ReportClient` instead of `Client` can be unit tested with
+/// [`MockClient`](crate::mock::MockClient), without a live endpoint.
+#[async_trait]
+pub trait ReportClient: Send + Sync {
+    /// Returns a list of available feeds, see `Client::get_feeds`.
+    async fn get_feeds(&self) -> Result<Vec<Feed>, ClientError>;
+
+    /// Returns a single report with the latest timestamp, see `Client::get_latest_report`.
+    async fn get_latest_report(&self, feed_id: ID) -> Result<ReportResponse, ClientError>;
+
+    /// Returns a single report at a given timestamp, see `Client::get_report`.
+    async fn get_report(&self, feed_id: ID, timestamp: u128)
+        -> Result<ReportResponse, ClientError>;
+
+    /// Returns a report for multiple FeedIDs at a given timestamp, see `Client::get_reports_bulk`.
+    async fn get_reports_bulk(
+        &self,
+        feed_ids: &[ID],
+        timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError>;
+
+    /// Returns multiple sequential reports for a single FeedID, starting at a given timestamp,
+    /// see `Client::get_reports_page`.
+    async fn get_reports_page(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError>;
+}
+
+#[async_trait]
+impl ReportClient for Client {
+    async fn get_feeds(&self) -> Result<Vec<Feed>, ClientError> {
+        Client::get_feeds(self).await
+    }
+
+    async fn get_latest_report(&self, feed_id: ID) -> Result<ReportResponse, ClientError> {
+        Client::get_latest_report(self, feed_id).await
+    }
+
+    async fn get_report(
+        &self,
+        feed_id: ID,
+        timestamp: u128,
+    ) -> Result<ReportResponse, ClientError> {
+        Client::get_report(self, feed_id, timestamp).await
+    }
+
+    async fn get_reports_bulk(
+        &self,
+        feed_ids: &[ID],
+        timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError> {
+        Client::get_reports_bulk(self, feed_ids, timestamp).await
+    }
+
+    async fn get_reports_page(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError> {
+        Client::get_reports_page(self, feed_id, start_timestamp).await
+    }
+}
+
+/// Runs a `Client` request until it completes or `cancel` is cancelled, whichever comes first.
+///
+/// On cancellation the request is dropped, which aborts it, and `ClientError::Cancelled` is
+/// returned. Any request method can be made cancellable this way, e.g.
+/// `with_cancellation(&cancel, client.get_latest_report(feed_id)).await`.
+pub async fn with_cancellation<T>(
+    cancel: &CancellationToken,
+    request: impl Future<Output = Result<T, ClientError>>,
+) -> Result<T, ClientError> {
+    tokio::select! {
+        biased;
+        _ = cancel.cancelled() => Err(ClientError::Cancelled),
+        result = request => result,
+    }
+}
+
+/// Recovers the addresses of the oracles that signed a full report payload.
+///
+/// Each signature in `rawRs`/`rawSs`/`rawVs` is recovered over
+/// `keccak256(keccak256(reportBlob) || reportContext)`, the digest the on-chain verifier checks.
+/// The addresses are returned in signature order. This does not decide whether the report is
+/// trusted: compare them against the DON's known signer set, and check there are enough of them.
+///
+/// # Parameters:
+/// * `full_report` - The ABI-encoded full report payload (the decoded `fullReport` of a `Report`).
+///
+/// # Errors
+///
+/// Returns `ClientError::InvalidReport` if the payload cannot be decoded, or
+/// `ClientError::InvalidSignature` if a signature is malformed or no signer can be recovered from it.
+pub fn verify_report_signatures(full_report: &[u8]) -> Result<Vec<[u8; 20]>, ClientError> {
+    let full_report = decode_full_report_strict(full_report)?;
+
+    if full_report.raw_rs.len() != full_report.raw_ss.len() {
+        return Err(ClientError::InvalidSignature {
+            index: full_report.raw_rs.len().min(full_report.raw_ss.len()),
+            reason: format!(
+                "{} rawRs but {} rawSs",
+                full_report.raw_rs.len(),
+                full_report.raw_ss.len()
+            ),
+        });
+    }
+
+    let mut hasher = Keccak256::new();
+    hasher.update(Keccak256::digest(&full_report.report_blob));
+    for word in full_report.report_context.to_words() {
+        hasher.update(word);
+    }
+    let digest = hasher.finalize();
+
+    full_report
+        .raw_rs
+        .iter()
+        .zip(&full_report.raw_ss)
+        .enumerate()
+        .map(|(index, (r, s))| {
+            let invalid = |reason: String| ClientError::InvalidSignature { index, reason };
+
+            let v = *full_report
+                .raw_vs
+                .get(index)
+                .ok_or_else(|| invalid("no rawVs byte for signature".to_string()))?;
+            let recovery_id = RecoveryId::from_byte(v)
+                .ok_or_else(|| invalid(format!("invalid recovery id {}", v)))?;
+            let signature = Signature::from_scalars(*r, *s).map_err(|e| invalid(e.to_string()))?;
+
+            let key = VerifyingKey::recover_from_prehash(&digest, &signature, recovery_id)
+                .map_err(|e| invalid(e.to_string()))?;
+
+            Ok(signer_address(&key))
+        })
+        .collect()
+}
+
+/// Returns the Ethereum address of a public key: the last 20 bytes of the keccak256 hash of its
+/// uncompressed encoding, without the `0x04` prefix.
+fn signer_address(key: &VerifyingKey) -> [u8; 20] {
+    let point = key.to_encoded_point(false);
+    let hash = Keccak256::digest(&point.as_bytes()[1..]);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chainlink_data_streams_report::report::{encode_full_report, ReportContext};
+    use k256::ecdsa::SigningKey;
+    use reqwest::header::HeaderValue;
+    use std::time::UNIX_EPOCH;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_api_error_is_retryable() {
+        let api_error = |status| ClientError::ApiError {
+            status,
+            body: String::new(),
+        };
+
+        assert!(api_error(500).is_retryable());
+        assert!(api_error(502).is_retryable());
+        assert!(api_error(503).is_retryable());
+        assert!(api_error(429).is_retryable());
+        assert!(ClientError::RateLimited { retry_after: None }.is_retryable());
+        assert!(!api_error(400).is_retryable());
+        assert!(!api_error(401).is_retryable());
+        assert!(!api_error(404).is_retryable());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+
+        let in_a_minute = SystemTime::now() + Duration::from_secs(60);
+        let retry_after = parse_retry_after(&httpdate::fmt_http_date(in_a_minute)).unwrap();
+        assert!(retry_after > Duration::from_secs(55) && retry_after <= Duration::from_secs(60));
+
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+        assert_eq!(parse_retry_after("-1"), None);
+    }
+
+    #[test]
+    fn test_non_transport_errors_are_not_retryable() {
+        let decode_error = serde_json::from_str::<FeedsResponse>("not json").unwrap_err();
+        assert!(!ClientError::InvalidResponseFormat(decode_error).is_retryable());
+
+        let header_error = HeaderValue::from_str("\n").unwrap_err();
+        assert!(
+            !ClientError::HmacError(HmacError::InvalidHeaderValue(header_error)).is_retryable()
+        );
+
+        // A clock set before the epoch surfaces as an error instead of a panic
+        let time_error = UNIX_EPOCH.duration_since(SystemTime::now()).unwrap_err();
+        assert!(!ClientError::from(HmacError::from(time_error)).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_connect_error_is_retryable() {
+        // Bind and immediately release a port so nothing is listening on it.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+
+        let err = HttpClient::new()
+            .get(format!("http://{}", address))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(ClientError::HttpRequestError(err).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_error_is_retryable() {
+        // Accept connections but never respond.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        let err = HttpClient::new()
+            .get(format!("http://{}", address))
+            .timeout(Duration::from_millis(50))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(err.is_timeout());
+        assert!(ClientError::HttpRequestError(err).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_http_timeout() {
+        // Accept connections but never respond.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        let config = Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            format!("http://{}", address),
+            "ws://localhost".to_string(),
+        )
+        .with_http_timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let err = client.get_feeds().await.unwrap_err();
+
+        assert!(matches!(err, ClientError::Timeout(_)));
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_cancellation() {
+        // Accept connections but never respond.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        let config = Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            format!("http://{}", address),
+            "ws://localhost".to_string(),
+        )
+        .build()
+        .unwrap();
+        let client = Client::new(config).unwrap();
+        let feed_id =
+            ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439")
+                .unwrap();
+
+        let cancel = CancellationToken::new();
+        let canceller = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            canceller.cancel();
+        });
+
+        let err = client
+            .get_reports_page_all_with_cancellation(feed_id, 0, 100, &cancel)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClientError::Cancelled));
+        assert!(!err.is_retryable());
+
+        // An already cancelled token fails any request immediately
+        let err = with_cancellation(&cancel, client.get_feeds())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClientError::Cancelled));
+    }
+
+    /// Signs `report_blob` with each of `signers` the way the DON does, returning the full report payload.
+    fn sign_full_report(report_blob: &[u8], signers: &[SigningKey]) -> Vec<u8> {
+        let report_context = ReportContext {
+            config_digest: [1; 32],
+            epoch_and_round: [2; 32],
+            extra_hash: [3; 32],
+        };
+
+        let mut hasher = Keccak256::new();
+        hasher.update(Keccak256::digest(report_blob));
+        for word in report_context.to_words() {
+            hasher.update(word);
+        }
+        let digest = hasher.finalize();
+
+        let mut raw_rs = Vec::new();
+        let mut raw_ss = Vec::new();
+        let mut raw_vs = [0u8; 32];
+        for (i, signer) in signers.iter().enumerate() {
+            let (signature, recovery_id) = signer.sign_prehash_recoverable(&digest).unwrap();
+            let (r, s) = signature.split_bytes();
+            raw_rs.push(r.into());
+            raw_ss.push(s.into());
+            raw_vs[i] = recovery_id.to_byte();
+        }
+
+        encode_full_report(&report_context, report_blob, &raw_rs, &raw_ss, raw_vs)
+    }
+
+    fn hex_address(address: &str) -> [u8; 20] {
+        hex::decode(address).unwrap().try_into().unwrap()
+    }
+
+    fn signing_key(byte: u8) -> SigningKey {
+        SigningKey::from_bytes(&[byte; 32].into()).unwrap()
+    }
+
+    #[test]
+    fn test_verify_report_signatures() {
+        let signers = [signing_key(1), signing_key(2), signing_key(3)];
+        let full_report = sign_full_report(b"mock report blob", &signers);
+
+        let recovered = verify_report_signatures(&full_report).unwrap();
+
+        let expected: Vec<[u8; 20]> = signers
+            .iter()
+            .map(|signer| signer_address(signer.verifying_key()))
+            .collect();
+        assert_eq!(recovered, expected);
+    }
+
+    #[test]
+    fn test_signer_address() {
+        // The well-known address of private key 1
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let key = SigningKey::from_bytes(&private_key.into()).unwrap();
+
+        assert_eq!(
+            hex::encode(signer_address(key.verifying_key())),
+            "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+
+    #[test]
+    fn test_verify_report_signatures_tampered() {
+        let signers = [signing_key(1)];
+        let mut full_report = sign_full_report(b"mock report blob", &signers);
+
+        // Flip a byte of the report blob, which starts after the 7 head words and its length word
+        full_report[8 * 32] ^= 0xff;
+
+        // Recovery over the tampered digest succeeds, but yields an unrelated address
+        let recovered = verify_report_signatures(&full_report).unwrap();
+        assert_eq!(
+            recovered,
+            vec![hex_address("aed90b0a6772cee66f231a06fad892e9fb699776")]
+        );
+        assert_ne!(recovered, vec![signer_address(signers[0].verifying_key())]);
+    }
+
+    #[test]
+    fn test_verify_report_signatures_invalid() {
+        assert!(matches!(
+            verify_report_signatures(&[0u8; 64]),
+            Err(ClientError::InvalidReport(_))
+        ));
+
+        let report_context = ReportContext::default();
+        let full_report =
+            encode_full_report(&report_context, b"blob", &[[0; 32]], &[[0; 32]], [0; 32]);
+        assert!(matches!(
+            verify_report_signatures(&full_report),
+            Err(ClientError::InvalidSignature { index: 0, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_with_http_client() {
+        // Answer requests from a single connection only, so a second connection would hang
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buffer = [0u8; 4096];
+            loop {
+                let mut request = Vec::new();
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = socket.read(&mut buffer).await.unwrap();
+                    if n == 0 {
+                        return;
+                    }
+                    request.extend_from_slice(&buffer[..n]);
+                }
+                let body = r#"{"feeds":[]}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        let config = |api_key: &str| {
+            Config::new(
+                api_key.to_string(),
+                "mock_secret".to_string(),
+                format!("http://{}", address),
+                "ws://localhost".to_string(),
+            )
+            .with_http_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap()
+        };
+
+        let http = HttpClient::new();
+        let first = Client::with_http_client(config("first_key"), http.clone());
+        let second = Client::with_http_client(config("second_key"), http);
+
+        // Both clients reuse the pooled connection
+        first.get_feeds().await.unwrap();
+        second.get_feeds().await.unwrap();
+        first.clone().get_feeds().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_builder_error_is_not_retryable() {
+        let err = HttpClient::new().get("not a url").send().await.unwrap_err();
+
+        assert!(!ClientError::HttpRequestError(err).is_retryable());
+    }
 }
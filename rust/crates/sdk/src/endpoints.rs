@@ -31,7 +31,7 @@ static AUTHZ_SIG_HEADER: OnceLock<HeaderName> = OnceLock::new();
 #[allow(dead_code)] // Currently unused
 static HOST_HEADER: OnceLock<HeaderName> = OnceLock::new();
 
-/// Functions to retrieve header constants, initializing them on first access
+// Functions to retrieve header constants, initializing them on first access
 
 #[allow(dead_code)] // Currently unused
 /// "X-Cll-Available-Origins"
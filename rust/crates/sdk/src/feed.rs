@@ -1,7 +1,7 @@
 use chainlink_data_streams_report::feed_id::ID;
 
-use byteorder::{BigEndian, ByteOrder};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents the feed report schema version.
 ///
@@ -58,8 +58,56 @@ impl Feed {
     /// assert_eq!(version, FeedVersion(1));
     /// ```
     pub fn version(&self) -> FeedVersion {
-        let version = BigEndian::read_u16(&self.feed_id.0[0..2]);
-        FeedVersion(version)
+        FeedVersion(self.feed_id.version())
+    }
+
+    /// Returns a human-readable label for the feed's report schema, e.g. `"v3 Crypto"`.
+    ///
+    /// Schemas without a documented description are labelled by version alone, e.g. `"v5"`.
+    /// Returns `"unknown"` for versions this SDK doesn't decode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chainlink_data_streams_report::feed_id::ID;
+    /// use chainlink_data_streams_sdk::feed::Feed;
+    ///
+    /// let feed_id = ID::from_hex_str("0x00036b4aa7e57ca7b68ae1bf45653f56b656fd3aa335ef7fae696b663f1b8472").unwrap();
+    /// let feed = Feed { feed_id };
+    /// assert_eq!(feed.schema_name(), "v3 Crypto");
+    /// ```
+    pub fn schema_name(&self) -> &'static str {
+        match self.version().0 {
+            1 => "v1",
+            2 => "v2",
+            3 => "v3 Crypto",
+            4 => "v4 RWA",
+            5 => "v5",
+            6 => "v6",
+            7 => "v7",
+            8 => "v8 Non-OTC RWA",
+            9 => "v9 NAV",
+            10 => "v10",
+            11 => "v11 Deutsche Boerse",
+            12 => "v12",
+            13 => "v13",
+            _ => "unknown",
+        }
+    }
+}
+
+/// The raw bytes of the feed's ID, see `ID::as_bytes`.
+impl AsRef<[u8]> for Feed {
+    fn as_ref(&self) -> &[u8] {
+        self.feed_id.as_ref()
+    }
+}
+
+/// Formats the feed as its hex ID followed by its schema label,
+/// e.g. `0x0003...8472 (v3 Crypto)`.
+impl fmt::Display for Feed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.feed_id, self.schema_name())
     }
 }
 
@@ -72,15 +120,15 @@ mod tests {
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V2_FEED_ID: ID = ID([
-        00, 02, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 2, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V3_FEED_ID: ID = ID([
-        00, 03, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 3, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
     const V4_FEED_ID: ID = ID([
-        00, 04, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
+        0, 4, 107, 74, 167, 229, 124, 167, 182, 138, 225, 191, 69, 101, 63, 86, 182, 86, 253, 58,
         163, 53, 239, 127, 174, 105, 107, 102, 63, 27, 132, 114,
     ]);
 
@@ -114,6 +162,61 @@ mod tests {
         assert_eq!(feed_v4.version(), FeedVersion(4));
     }
 
+    #[test]
+    fn test_schema_name() {
+        let mut unknown_feed_id = V3_FEED_ID;
+        unknown_feed_id.0[1] = 0xff;
+
+        let expected = [
+            "v1",
+            "v2",
+            "v3 Crypto",
+            "v4 RWA",
+            "v5",
+            "v6",
+            "v7",
+            "v8 Non-OTC RWA",
+            "v9 NAV",
+            "v10",
+            "v11 Deutsche Boerse",
+            "v12",
+            "v13",
+        ];
+
+        for (version, expected) in (1u16..).zip(expected) {
+            let mut feed_id = V3_FEED_ID;
+            feed_id.0[..2].copy_from_slice(&version.to_be_bytes());
+            assert_eq!(Feed { feed_id }.version(), FeedVersion(version));
+            assert_eq!(Feed { feed_id }.schema_name(), expected);
+        }
+
+        assert_eq!(
+            Feed {
+                feed_id: unknown_feed_id
+            }
+            .schema_name(),
+            "unknown"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let feed = Feed {
+            feed_id: V3_FEED_ID,
+        };
+
+        assert_eq!(feed.to_string(), format!("{} (v3 Crypto)", V3_FEED_ID_STR));
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let feed = Feed {
+            feed_id: V3_FEED_ID,
+        };
+
+        assert_eq!(feed.as_ref(), V3_FEED_ID.as_bytes());
+    }
+
     #[test]
     fn test_serialize() {
         let feeds = vec![
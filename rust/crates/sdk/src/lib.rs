@@ -1,6 +1,12 @@
-mod auth;
+pub mod auth;
 pub mod client;
 pub mod config;
 mod endpoints;
 pub mod feed;
+pub mod mock;
+mod page_stream;
+pub mod source;
 pub mod stream;
+
+// Report types and decoders, so SDK users don't need to depend on the report crate directly.
+pub use chainlink_data_streams_report::{feed_id, report};
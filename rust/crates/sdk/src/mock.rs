@@ -0,0 +1,228 @@
+//! An in-memory [`ReportClient`] for unit testing code that queries reports, without a live
+//! endpoint.
+
+use crate::client::{ClientError, ReportClient, ReportResponse};
+use crate::feed::Feed;
+
+use chainlink_data_streams_report::feed_id::ID;
+use chainlink_data_streams_report::report::Report;
+
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+
+/// A [`ReportClient`] answering from preconfigured reports.
+///
+/// Queries behave like the REST API: a report is found by its feed ID and observations timestamp,
+/// and a missing report is a `ClientError::ApiError` with status `404`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chainlink_data_streams_sdk::client::ReportClient;
+/// use chainlink_data_streams_sdk::mock::MockClient;
+/// use chainlink_data_streams_sdk::report::Report;
+/// use chainlink_data_streams_sdk::feed_id::ID;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let feed_id = ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439").unwrap();
+/// let client = MockClient::new().with_report(Report {
+///     feed_id,
+///     valid_from_timestamp: 1718885772,
+///     observations_timestamp: 1718885772,
+///     full_report: "0x".to_string(),
+/// });
+///
+/// let response = client.get_latest_report(feed_id).await.unwrap();
+/// assert_eq!(response.report.observations_timestamp, 1718885772);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    /// Reports of each feed, keyed by observations timestamp
+    reports: HashMap<ID, BTreeMap<usize, Report>>,
+}
+
+impl MockClient {
+    /// Creates a `MockClient` without any reports.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a report, replacing any report of the same feed with the same observations timestamp.
+    pub fn with_report(mut self, report: Report) -> Self {
+        self.reports
+            .entry(report.feed_id)
+            .or_default()
+            .insert(report.observations_timestamp, report);
+        self
+    }
+
+    /// Adds several reports, see [`MockClient::with_report`].
+    pub fn with_reports(self, reports: impl IntoIterator<Item = Report>) -> Self {
+        reports.into_iter().fold(self, MockClient::with_report)
+    }
+
+    fn report_at(&self, feed_id: &ID, timestamp: u128) -> Option<&Report> {
+        let timestamp = usize::try_from(timestamp).ok()?;
+        self.reports.get(feed_id)?.get(&timestamp)
+    }
+}
+
+fn not_found(feed_id: &ID) -> ClientError {
+    ClientError::ApiError {
+        status: 404,
+        body: format!("No report found for feed {}", feed_id),
+    }
+}
+
+#[async_trait]
+impl ReportClient for MockClient {
+    /// Returns the feeds with at least one report, in no particular order.
+    async fn get_feeds(&self) -> Result<Vec<Feed>, ClientError> {
+        Ok(self
+            .reports
+            .keys()
+            .map(|feed_id| Feed { feed_id: *feed_id })
+            .collect())
+    }
+
+    async fn get_latest_report(&self, feed_id: ID) -> Result<ReportResponse, ClientError> {
+        let report = self
+            .reports
+            .get(&feed_id)
+            .and_then(|reports| reports.values().next_back())
+            .ok_or_else(|| not_found(&feed_id))?;
+
+        Ok(ReportResponse {
+            report: report.clone(),
+        })
+    }
+
+    async fn get_report(
+        &self,
+        feed_id: ID,
+        timestamp: u128,
+    ) -> Result<ReportResponse, ClientError> {
+        let report = self
+            .report_at(&feed_id, timestamp)
+            .ok_or_else(|| not_found(&feed_id))?;
+
+        Ok(ReportResponse {
+            report: report.clone(),
+        })
+    }
+
+    /// Returns the reports found, leaving out feeds without a report at `timestamp`.
+    async fn get_reports_bulk(
+        &self,
+        feed_ids: &[ID],
+        timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError> {
+        Ok(feed_ids
+            .iter()
+            .filter_map(|feed_id| self.report_at(feed_id, timestamp))
+            .cloned()
+            .collect())
+    }
+
+    /// Returns every report of the feed from `start_timestamp` on, in ascending order.
+    async fn get_reports_page(
+        &self,
+        feed_id: ID,
+        start_timestamp: u128,
+    ) -> Result<Vec<Report>, ClientError> {
+        let start_timestamp = usize::try_from(start_timestamp).unwrap_or(usize::MAX);
+
+        Ok(self
+            .reports
+            .get(&feed_id)
+            .map(|reports| {
+                reports
+                    .range(start_timestamp..)
+                    .map(|(_, r)| r.clone())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED_ID_1: &str = "0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439";
+    const FEED_ID_2: &str = "0x000359843a543ee2fe414dc14c7e7920ef10f4372990b79d6361cdc0dd1ba782";
+
+    fn report(feed_id: &str, timestamp: usize) -> Report {
+        Report {
+            feed_id: ID::from_hex_str(feed_id).unwrap(),
+            valid_from_timestamp: timestamp,
+            observations_timestamp: timestamp,
+            full_report: "0x".to_string(),
+        }
+    }
+
+    fn mock_client() -> MockClient {
+        MockClient::new().with_reports([
+            report(FEED_ID_1, 20),
+            report(FEED_ID_1, 10),
+            report(FEED_ID_1, 30),
+            report(FEED_ID_2, 10),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_mock_client_queries() {
+        let client = mock_client();
+        let feed_id_1 = ID::from_hex_str(FEED_ID_1).unwrap();
+        let feed_id_2 = ID::from_hex_str(FEED_ID_2).unwrap();
+
+        assert_eq!(client.get_feeds().await.unwrap().len(), 2);
+
+        let latest = client.get_latest_report(feed_id_1).await.unwrap();
+        assert_eq!(latest.report, report(FEED_ID_1, 30));
+
+        let at = client.get_report(feed_id_1, 20).await.unwrap();
+        assert_eq!(at.report, report(FEED_ID_1, 20));
+
+        let bulk = client
+            .get_reports_bulk(&[feed_id_1, feed_id_2], 10)
+            .await
+            .unwrap();
+        assert_eq!(bulk, vec![report(FEED_ID_1, 10), report(FEED_ID_2, 10)]);
+
+        let page = client.get_reports_page(feed_id_1, 15).await.unwrap();
+        assert_eq!(page, vec![report(FEED_ID_1, 20), report(FEED_ID_1, 30)]);
+    }
+
+    #[tokio::test]
+    async fn test_mock_client_missing_reports() {
+        let client = mock_client();
+        let feed_id_1 = ID::from_hex_str(FEED_ID_1).unwrap();
+        let unknown =
+            ID::from_hex_str("0x0003000000000000000000000000000000000000000000000000000000000000")
+                .unwrap();
+
+        assert!(matches!(
+            client.get_latest_report(unknown).await,
+            Err(ClientError::ApiError { status: 404, .. })
+        ));
+        assert!(matches!(
+            client.get_report(feed_id_1, 15).await,
+            Err(ClientError::ApiError { status: 404, .. })
+        ));
+        assert_eq!(
+            client
+                .get_reports_bulk(&[feed_id_1, unknown], 20)
+                .await
+                .unwrap(),
+            vec![report(FEED_ID_1, 20)]
+        );
+        assert!(client
+            .get_reports_page(unknown, 0)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+//! Incremental parsing of `{"reports": [...]}` response bodies, so a page can be consumed report
+//! by report as it arrives instead of being buffered whole.
+
+use crate::client::ClientError;
+
+use chainlink_data_streams_report::report::Report;
+
+use futures::{stream, Stream, StreamExt};
+use serde::de::Error as _;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Scanning the top-level object for the `"reports"` array.
+    Seek,
+    /// Inside the `"reports"` array.
+    Array,
+    /// Past the end of the `"reports"` array; the rest of the body is ignored.
+    Done,
+}
+
+/// Extracts the reports of the top-level `"reports"` array from a JSON body fed in chunks.
+///
+/// Only the bytes of the report currently being read are retained, so memory use is bounded by
+/// the largest single report rather than the whole body.
+pub(crate) struct ReportsArrayParser {
+    buf: Vec<u8>,
+    /// Index in `buf` of the next byte to scan.
+    pos: usize,
+    /// Index in `buf` where the report being read starts.
+    start: Option<usize>,
+    phase: Phase,
+    /// Nesting depth within the body while seeking, or within the current report in the array.
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// Whether the next string in the top-level object is a key.
+    expect_key: bool,
+    key: Vec<u8>,
+    /// Whether the current top-level key is `"reports"`.
+    reports_key: bool,
+}
+
+impl ReportsArrayParser {
+    pub(crate) fn new() -> Self {
+        ReportsArrayParser {
+            buf: Vec::new(),
+            pos: 0,
+            start: None,
+            phase: Phase::Seek,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            expect_key: false,
+            key: Vec::new(),
+            reports_key: false,
+        }
+    }
+
+    /// Feeds the next chunk of the body, returning the reports it completed.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Result<Vec<Report>, serde_json::Error> {
+        let mut reports = Vec::new();
+
+        if self.phase == Phase::Done {
+            return Ok(reports);
+        }
+
+        self.buf.extend_from_slice(chunk);
+
+        while self.pos < self.buf.len() && self.phase != Phase::Done {
+            let byte = self.buf[self.pos];
+            self.pos += 1;
+
+            let reading_key = self.phase == Phase::Seek && self.depth == 1 && self.expect_key;
+
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                    if reading_key {
+                        self.reports_key = self.key == b"reports";
+                    }
+                    continue;
+                }
+                if reading_key {
+                    self.key.push(byte);
+                }
+                continue;
+            }
+
+            match self.phase {
+                Phase::Seek => match byte {
+                    b'"' => {
+                        self.in_string = true;
+                        self.key.clear();
+                    }
+                    b'[' if self.depth == 1 && self.reports_key && !self.expect_key => {
+                        self.phase = Phase::Array;
+                        self.depth = 0;
+                    }
+                    b'{' | b'[' => {
+                        self.depth += 1;
+                        if self.depth == 1 {
+                            self.expect_key = byte == b'{';
+                        }
+                    }
+                    b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                    b':' if self.depth == 1 => self.expect_key = false,
+                    b',' if self.depth == 1 => {
+                        self.expect_key = true;
+                        self.reports_key = false;
+                    }
+                    _ => {}
+                },
+                Phase::Array if self.depth == 0 => match byte {
+                    b'{' => {
+                        self.start = Some(self.pos - 1);
+                        self.depth = 1;
+                    }
+                    b']' => self.phase = Phase::Done,
+                    b',' | b' ' | b'\t' | b'\n' | b'\r' => {}
+                    _ => {
+                        return Err(serde_json::Error::custom(
+                            "expected a report object in the reports array",
+                        ))
+                    }
+                },
+                Phase::Array => match byte {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b'}' | b']' => {
+                        self.depth -= 1;
+                        if self.depth == 0 {
+                            let start = self.start.take().expect("report start is recorded");
+                            reports.push(serde_json::from_slice(&self.buf[start..self.pos])?);
+                        }
+                    }
+                    _ => {}
+                },
+                Phase::Done => {}
+            }
+        }
+
+        // Drop everything scanned that isn't part of a report still being read
+        let keep_from = match self.phase {
+            Phase::Done => self.buf.len(),
+            _ => self.start.unwrap_or(self.pos),
+        };
+        self.buf.drain(..keep_from);
+        self.pos -= keep_from.min(self.pos);
+        if let Some(start) = &mut self.start {
+            *start = 0;
+        }
+
+        Ok(reports)
+    }
+
+    /// Checks that the body contained a complete `"reports"` array, once it has been fully fed.
+    pub(crate) fn finish(&self) -> Result<(), serde_json::Error> {
+        match self.phase {
+            Phase::Done => Ok(()),
+            _ => Err(serde_json::Error::custom(
+                "response ended before the reports array was closed",
+            )),
+        }
+    }
+}
+
+/// Yields the reports of a `{"reports": [...]}` body as its chunks arrive.
+///
+/// The stream ends after the first error.
+pub(crate) fn reports_stream<S, B>(chunks: S) -> impl Stream<Item = Result<Report, ClientError>>
+where
+    S: Stream<Item = Result<B, reqwest::Error>>,
+    B: AsRef<[u8]>,
+{
+    let state = (Box::pin(chunks), ReportsArrayParser::new(), VecDeque::new());
+
+    stream::unfold(Some(state), |state| async move {
+        let (mut chunks, mut parser, mut pending) = state?;
+
+        loop {
+            if let Some(report) = pending.pop_front() {
+                return Some((Ok(report), Some((chunks, parser, pending))));
+            }
+
+            match chunks.next().await {
+                Some(Ok(chunk)) => match parser.push(chunk.as_ref()) {
+                    Ok(reports) => pending.extend(reports),
+                    Err(e) => return Some((Err(e.into()), None)),
+                },
+                Some(Err(e)) => return Some((Err(e.into()), None)),
+                None => return parser.finish().err().map(|e| (Err(e.into()), None)),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED_ID: &str = "0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439";
+
+    fn report_json(ts: usize) -> String {
+        format!(
+            r#"{{"feedID":"{}","validFromTimestamp":{},"observationsTimestamp":{},"fullReport":"0x00"}}"#,
+            FEED_ID, ts, ts
+        )
+    }
+
+    fn parse_in_chunks(body: &str, chunk_size: usize) -> Result<Vec<usize>, serde_json::Error> {
+        let mut parser = ReportsArrayParser::new();
+        let mut timestamps = Vec::new();
+
+        for chunk in body.as_bytes().chunks(chunk_size) {
+            for report in parser.push(chunk)? {
+                timestamps.push(report.observations_timestamp);
+            }
+        }
+        parser.finish()?;
+
+        Ok(timestamps)
+    }
+
+    #[test]
+    fn test_reports_array_parser_any_chunking() {
+        // Other keys before and after the array, including a string value that reads "reports"
+        let body = format!(
+            r#"{{"note": "reports", "escaped": "}}{{[\"]", "meta": {{"reports": [1, "]"]}}, "reports" : [ {}, {} ,{}], "next": null}}"#,
+            report_json(1),
+            report_json(2),
+            report_json(3)
+        );
+
+        for chunk_size in 1..=body.len() {
+            assert_eq!(parse_in_chunks(&body, chunk_size).unwrap(), vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn test_reports_array_parser_bounds_buffer() {
+        let mut parser = ReportsArrayParser::new();
+        parser.push(br#"{"reports":["#).unwrap();
+
+        for ts in 0..100 {
+            let reports = parser
+                .push(format!("{},", report_json(ts)).as_bytes())
+                .unwrap();
+            assert_eq!(reports.len(), 1);
+            assert!(parser.buf.is_empty());
+        }
+
+        parser.push(b"]}").unwrap();
+        parser.finish().unwrap();
+    }
+
+    #[test]
+    fn test_reports_array_parser_errors() {
+        assert_eq!(
+            parse_in_chunks(r#"{"reports":[]}"#, 3).unwrap(),
+            Vec::<usize>::new()
+        );
+
+        // Truncated body, missing array and malformed elements
+        let truncated = format!(r#"{{"reports":[{}"#, report_json(1));
+        assert!(parse_in_chunks(&truncated, 7).is_err());
+        assert!(parse_in_chunks(r#"{"feeds":[]}"#, 4).is_err());
+        assert!(parse_in_chunks(r#"{"reports":[1]}"#, 4).is_err());
+        assert!(parse_in_chunks(r#"{"reports":[{"feedID":"0x01"}]}"#, 4).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::client::{Client, ClientError};
+use crate::stream::{Stream, StreamError};
+
+use chainlink_data_streams_report::feed_id::ID;
+use chainlink_data_streams_report::report::Report;
+
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use thiserror::Error;
+use tokio::time::{interval, Duration, Interval, MissedTickBehavior};
+
+/// Errors that can occur while reading from a `ReportSource`.
+#[derive(Error, Debug)]
+pub enum SdkError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error(transparent)]
+    Stream(#[from] StreamError),
+}
+
+/// A source of reports, independent of the underlying transport.
+///
+/// Implemented by [`PollingSource`] (REST) and [`Stream`] (WebSocket), so consumer code can be
+/// written once and run against either. Both yield each report at most once per feed and
+/// observations timestamp: `Stream` deduplicates across HA connections, `PollingSource` skips
+/// reports it has already returned.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use chainlink_data_streams_sdk::source::{ReportSource, SdkError};
+///
+/// async fn consume(source: &mut impl ReportSource) -> Result<(), SdkError> {
+///     while let Some(report) = source.next_report().await? {
+///         println!("{} @ {}", report.feed_id, report.observations_timestamp);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[async_trait]
+pub trait ReportSource: Send {
+    /// Waits for and returns the next report.
+    ///
+    /// Returns `Ok(None)` once the source is exhausted and will not produce any more reports.
+    /// An error does not exhaust the source; calling `next_report` again retries.
+    async fn next_report(&mut self) -> Result<Option<Report>, SdkError>;
+}
+
+#[async_trait]
+impl ReportSource for Stream {
+    /// Reads the next report from the Stream. `listen` must have been called beforehand.
+    async fn next_report(&mut self) -> Result<Option<Report>, SdkError> {
+        match self.read().await {
+            Ok(response) => Ok(Some(response.report)),
+            Err(StreamError::StreamClosed) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// A `ReportSource` that polls the latest report of each feed over REST at a fixed interval.
+///
+/// A report is only returned if it is newer than the last report returned for the same feed,
+/// so polling faster than a feed updates does not produce duplicates.
+pub struct PollingSource {
+    client: Client,
+    feed_ids: Vec<ID>,
+    interval: Interval,
+    pending: VecDeque<Report>,
+    last_seen: HashMap<ID, usize>,
+}
+
+impl PollingSource {
+    /// Creates a new `PollingSource` polling `feed_ids` every `poll_interval`.
+    /// The first poll happens immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poll_interval` is zero.
+    pub fn new(client: Client, feed_ids: Vec<ID>, poll_interval: Duration) -> Self {
+        let mut interval = interval(poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        PollingSource {
+            client,
+            feed_ids,
+            interval,
+            pending: VecDeque::new(),
+            last_seen: HashMap::new(),
+        }
+    }
+
+    async fn poll(&mut self) -> Result<(), ClientError> {
+        for feed_id in &self.feed_ids {
+            let report = self.client.get_latest_report(*feed_id).await?.report;
+
+            let is_new = self
+                .last_seen
+                .get(feed_id)
+                .map_or(true, |last| report.observations_timestamp > *last);
+
+            if is_new {
+                self.last_seen
+                    .insert(*feed_id, report.observations_timestamp);
+                self.pending.push_back(report);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ReportSource for PollingSource {
+    async fn next_report(&mut self) -> Result<Option<Report>, SdkError> {
+        if self.feed_ids.is_empty() {
+            return Ok(None);
+        }
+
+        loop {
+            if let Some(report) = self.pending.pop_front() {
+                return Ok(Some(report));
+            }
+
+            self.interval.tick().await;
+            self.poll().await?;
+        }
+    }
+}
@@ -2,37 +2,52 @@ mod establish_connection;
 mod monitor_connection;
 
 use establish_connection::connect;
-use monitor_connection::run_stream;
+use monitor_connection::{
+    budget_permits, buffered_size, run_stream, CircuitBreakerState, StreamContext, WaterMark,
+};
 
+use crate::client::ReportResponse;
 use crate::config::Config;
 
 use chainlink_data_streams_report::feed_id::ID;
 use chainlink_data_streams_report::report::Report;
 
+use futures::{ready, stream::FusedStream};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
+    future::Future,
+    pin::Pin,
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
+    task::{Context, Poll},
 };
 use tokio::{
     net::TcpStream,
-    sync::{broadcast, mpsc, Mutex},
-    time::{sleep, Duration},
+    sync::{broadcast, mpsc, Mutex, Semaphore},
+    task::JoinHandle,
+    time::{timeout_at, Duration, Instant},
+};
+use tokio_tungstenite::{
+    tungstenite::Error as WsError, MaybeTlsStream, WebSocketStream as TungsteniteWebSocketStream,
 };
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream as TungsteniteWebSocketStream};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 pub const DEFAULT_WS_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Default initial WebSocket reconnection backoff, see `ConfigBuilder::with_ws_reconnect_interval`.
 pub const MIN_WS_RECONNECT_INTERVAL: Duration = Duration::from_millis(1000);
+/// Default maximum WebSocket reconnection backoff, see `ConfigBuilder::with_ws_reconnect_interval`.
 pub const MAX_WS_RECONNECT_INTERVAL: Duration = Duration::from_millis(10000);
+/// How long `Stream::close` waits for the connection tasks to stop before aborting them.
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Debug, thiserror::Error)]
 pub enum StreamError {
+    /// Boxed, as a tungstenite error is much larger than the other variants.
     #[error("WebSocket error: {0}")]
-    WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
+    WebSocketError(#[source] Box<WsError>),
 
     #[error("Connection error: {0}")]
     ConnectionError(String),
@@ -45,13 +60,137 @@ pub enum StreamError {
 
     #[error("Stream closed")]
     StreamClosed,
+
+    #[error("Connection closed by server: code={code}, reason={reason}")]
+    ClosedByServer { code: u16, reason: String },
+}
+
+impl From<WsError> for StreamError {
+    fn from(e: WsError) -> Self {
+        StreamError::WebSocketError(Box::new(e))
+    }
+}
+
+impl StreamError {
+    /// Returns `true` if the connection is worth re-establishing after this error.
+    ///
+    /// Dropped connections, I/O failures and `5xx` handshake responses are considered transient.
+    /// A handshake rejected with a `4xx` status (e.g. `401` for invalid credentials), HMAC failures
+    /// and malformed messages are not, since reconnecting would be rejected the same way.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StreamError::WebSocketError(e) => match e.as_ref() {
+                WsError::Http(response) => {
+                    let status = response.status();
+                    status.is_server_error() || status.as_u16() == 429
+                }
+                WsError::Io(_)
+                | WsError::ConnectionClosed
+                | WsError::AlreadyClosed
+                | WsError::Protocol(_) => true,
+                _ => false,
+            },
+            StreamError::ConnectionError(_) => true,
+            StreamError::AuthError(_)
+            | StreamError::SerializationError(_)
+            | StreamError::StreamClosed
+            | StreamError::ClosedByServer { .. } => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WebSocketReport {
     pub report: Report,
+
+    /// The original frame the report was parsed from.
+    /// Only set when the `Config` was built with `with_retain_raw(true)`.
+    #[serde(skip)]
+    pub raw: Option<Vec<u8>>,
+}
+
+/// Lets reports from the Stream be handled by code written against REST responses.
+/// The raw frame, if retained, is dropped.
+impl From<WebSocketReport> for ReportResponse {
+    fn from(websocket_report: WebSocketReport) -> Self {
+        ReportResponse {
+            report: websocket_report.report,
+        }
+    }
 }
 
+impl From<ReportResponse> for WebSocketReport {
+    fn from(response: ReportResponse) -> Self {
+        WebSocketReport {
+            report: response.report,
+            raw: None,
+        }
+    }
+}
+
+/// State of the Stream circuit breaker, see `ConfigBuilder::with_circuit_breaker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CircuitState {
+    /// Reconnecting as usual
+    #[default]
+    Closed,
+    /// Reconnects are paused for the cooldown
+    Open,
+    /// A single probe connection is being attempted after the cooldown
+    HalfOpen,
+}
+
+/// Connection health of a Stream, see `Stream::health`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamHealth {
+    /// All configured connections are active
+    Healthy,
+    /// Some, but not all, configured connections are active
+    Degraded,
+    /// No connection is active
+    Down,
+}
+
+impl StreamHealth {
+    fn from_connections(active_connections: usize, configured_connections: usize) -> Self {
+        if active_connections == 0 {
+            StreamHealth::Down
+        } else if active_connections >= configured_connections {
+            StreamHealth::Healthy
+        } else {
+            StreamHealth::Degraded
+        }
+    }
+}
+
+/// Connection lifecycle events published by a Stream, see `Stream::subscribe_events`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Repeated full reconnects opened the circuit; reconnects are paused for the cooldown
+    CircuitOpen,
+    /// The cooldown elapsed and a probe connection is being attempted
+    CircuitHalfOpen,
+    /// A report was accepted after the circuit had opened
+    CircuitClosed,
+}
+
+/// Report and connection events of a Stream, passed to the hook set with
+/// `ConfigBuilder::with_stream_event_hook` as they happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamEvent {
+    /// A report was accepted and queued for the consumer
+    ReportAccepted { feed_id: ID },
+    /// A report was dropped as a duplicate of one already accepted
+    ReportDeduplicated { feed_id: ID },
+    /// A connection is being re-established while others are still active
+    PartialReconnect,
+    /// A connection is being re-established after all connections were lost
+    FullReconnect,
+    /// A connection was closed, by the server, an error or `Stream::close`
+    ConnectionClosed,
+}
+
+#[derive(Default)]
 struct Stats {
     /// Total number of accepted reports
     accepted: AtomicUsize,
@@ -65,11 +204,19 @@ struct Stats {
     configured_connections: AtomicUsize,
     /// Current number of active connections     
     active_connections: AtomicUsize,
+    /// Total number of times the report channel crossed the high-water mark
+    channel_highwater_events: AtomicUsize,
+    /// Whether the report channel is currently above the high-water mark
+    channel_above_highwater: AtomicBool,
+    /// Circuit breaker state and consecutive full reconnect count
+    circuit: CircuitBreakerState,
+    /// Total size in bytes of reports in the report channel
+    buffered_bytes: AtomicUsize,
 }
 
 #[derive(Debug)]
 pub enum WebSocketConnection {
-    Single(TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>),
+    Single(Box<TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>>),
     Multiple(Vec<TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>>),
 }
 
@@ -81,11 +228,21 @@ pub struct Stream {
     config: Config,
     feed_ids: Vec<ID>,
     conn: Option<WebSocketConnection>,
-    report_sender: mpsc::Sender<WebSocketReport>,
-    report_receiver: mpsc::Receiver<WebSocketReport>,
+    /// Connections opened by `subscribe_additional` before `listen`, with the feed IDs of each
+    additional_conns: Vec<(WebSocketConnection, Vec<ID>)>,
+    report_sender: Option<mpsc::Sender<WebSocketReport>>,
+    /// Lets `subscribe_additional` hand a sender to new connections without keeping the channel open
+    weak_report_sender: mpsc::WeakSender<WebSocketReport>,
+    report_receiver: Option<mpsc::Receiver<WebSocketReport>>,
     shutdown_sender: broadcast::Sender<()>,
+    event_sender: broadcast::Sender<ConnectionEvent>,
     stats: Arc<Stats>,
-    water_mark: Arc<Mutex<HashMap<String, usize>>>,
+    water_mark: Arc<Mutex<HashMap<ID, WaterMark>>>,
+    buffer_budget: Option<Arc<Semaphore>>,
+    terminal_error: Arc<Mutex<Option<StreamError>>>,
+    /// Connection tasks spawned by `listen` and `subscribe_additional`, and the report handler task
+    /// spawned by `on_report`, joined by `close`
+    tasks: Vec<JoinHandle<()>>,
 }
 
 impl Stream {
@@ -129,8 +286,9 @@ impl Stream {
     /// | **401 Unauthorized User** | This error is triggered when:<br>- Authentication fails, typically because the HMAC signature provided by the client doesn't match the one expected by the server.<br>- A user requests access to a feed without the appropriate permission or that does not exist. |
     /// | **500 Internal Server** | Indicates an unexpected condition encountered by the server, preventing it from fulfilling the request. This error typically points to issues on the server side. |
     pub async fn new(config: &Config, feed_ids: Vec<ID>) -> Result<Stream, StreamError> {
-        let (report_sender, report_receiver) = mpsc::channel(100);
+        let (report_sender, report_receiver) = mpsc::channel(config.stream_buffer_size);
         let (shutdown_sender, _) = broadcast::channel(1);
+        let (event_sender, _) = broadcast::channel(16);
 
         let stats = Arc::new(Stats {
             accepted: AtomicUsize::new(0),
@@ -139,24 +297,43 @@ impl Stream {
             full_reconnects: AtomicUsize::new(0),
             configured_connections: AtomicUsize::new(0),
             active_connections: AtomicUsize::new(0),
+            channel_highwater_events: AtomicUsize::new(0),
+            channel_above_highwater: AtomicBool::new(false),
+            circuit: CircuitBreakerState::default(),
+            buffered_bytes: AtomicUsize::new(0),
         });
 
         let conn = connect(config, &feed_ids, stats.clone()).await?;
 
         let water_mark = Arc::new(Mutex::new(HashMap::new()));
 
+        let buffer_budget = config
+            .max_buffered_bytes
+            .map(|max| Arc::new(Semaphore::new(max.min(Semaphore::MAX_PERMITS))));
+
         Ok(Stream {
             config: config.clone(),
             feed_ids,
             conn: Some(conn),
-            report_sender,
-            report_receiver,
+            additional_conns: Vec::new(),
+            weak_report_sender: report_sender.downgrade(),
+            report_sender: Some(report_sender),
+            report_receiver: Some(report_receiver),
             shutdown_sender,
+            event_sender,
             stats,
             water_mark,
+            buffer_budget,
+            terminal_error: Arc::new(Mutex::new(None)),
+            tasks: Vec::new(),
         })
     }
 
+    /// Returns the feed IDs the Stream is subscribed to.
+    pub fn feed_ids(&self) -> &[ID] {
+        &self.feed_ids
+    }
+
     /// Starts listening for reports on the Stream.
     /// This method will spawn a new task for each WebSocket connection.
     pub async fn listen(&mut self) -> Result<(), StreamError> {
@@ -165,65 +342,262 @@ impl Stream {
             .take()
             .ok_or_else(|| StreamError::ConnectionError("No connection".into()))?;
 
+        // Only the connection tasks hold a sender from here on, so the channel closes once they all stop
+        let report_sender = self
+            .report_sender
+            .take()
+            .ok_or_else(|| StreamError::ConnectionError("No connection".into()))?;
+
+        // Feed IDs added by `subscribe_additional` belong to their own connections
+        let additional_conns = std::mem::take(&mut self.additional_conns);
+        let feed_ids: Vec<ID> = self
+            .feed_ids
+            .iter()
+            .filter(|feed_id| {
+                !additional_conns
+                    .iter()
+                    .any(|(_, ids)| ids.contains(feed_id))
+            })
+            .copied()
+            .collect();
+        self.spawn_connections(conn, &report_sender, &feed_ids);
+
+        for (conn, feed_ids) in additional_conns {
+            self.spawn_connections(conn, &report_sender, &feed_ids);
+        }
+
+        Ok(())
+    }
+
+    /// Subscribes the Stream to additional feed IDs at runtime, without interrupting its existing
+    /// connections.
+    ///
+    /// A connection's feed IDs are fixed when it is established, so a new connection (one per
+    /// origin in HA mode) is opened for the feed IDs the Stream is not subscribed to yet. Its
+    /// reports are delivered to the same consumer and deduplicated against the same watermarks.
+    /// If the Stream is not listening yet, the connection starts with [`Stream::listen`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamError::StreamClosed` if all connections of a listening Stream have stopped,
+    /// or the error that prevented connecting.
+    pub async fn subscribe_additional(&mut self, feed_ids: Vec<ID>) -> Result<(), StreamError> {
+        let mut new_feed_ids: Vec<ID> = Vec::new();
+        for feed_id in feed_ids {
+            if !self.feed_ids.contains(&feed_id) && !new_feed_ids.contains(&feed_id) {
+                new_feed_ids.push(feed_id);
+            }
+        }
+
+        if new_feed_ids.is_empty() {
+            return Ok(());
+        }
+
+        if self.conn.is_some() {
+            let conn = connect(&self.config, &new_feed_ids, self.stats.clone()).await?;
+            self.additional_conns.push((conn, new_feed_ids.clone()));
+        } else {
+            let report_sender = self
+                .weak_report_sender
+                .upgrade()
+                .ok_or(StreamError::StreamClosed)?;
+            let conn = connect(&self.config, &new_feed_ids, self.stats.clone()).await?;
+            self.spawn_connections(conn, &report_sender, &new_feed_ids);
+        }
+
+        info!("Subscribed to {} additional feed(s).", new_feed_ids.len());
+        self.feed_ids.extend(new_feed_ids);
+
+        Ok(())
+    }
+
+    /// Spawns a task for each WebSocket stream of `conn`, subscribed to `feed_ids`.
+    fn spawn_connections(
+        &mut self,
+        conn: WebSocketConnection,
+        report_sender: &mpsc::Sender<WebSocketReport>,
+        feed_ids: &[ID],
+    ) {
         match conn {
             WebSocketConnection::Single(stream) => {
-                let report_sender = self.report_sender.clone();
-                let shutdown_receiver = self.shutdown_sender.subscribe();
-                let stats = self.stats.clone();
-                let water_mark = self.water_mark.clone();
-                let config = self.config.clone();
-                let feed_ids = self.feed_ids.clone();
-
-                tokio::spawn(run_stream(
-                    stream,
-                    report_sender,
-                    shutdown_receiver,
-                    stats,
-                    water_mark,
-                    config,
-                    feed_ids,
-                ));
+                self.spawn_connection(*stream, report_sender.clone(), feed_ids.to_vec());
             }
             WebSocketConnection::Multiple(streams) => {
                 for stream in streams {
-                    let report_sender = self.report_sender.clone();
-                    let shutdown_receiver = self.shutdown_sender.subscribe();
-                    let stats = self.stats.clone();
-                    let water_mark = self.water_mark.clone();
-                    let config = self.config.clone();
-                    let feed_ids = self.feed_ids.clone();
-
-                    tokio::spawn(run_stream(
-                        stream,
-                        report_sender,
-                        shutdown_receiver,
-                        stats,
-                        water_mark,
-                        config,
-                        feed_ids,
-                    ));
+                    self.spawn_connection(stream, report_sender.clone(), feed_ids.to_vec());
                 }
             }
         }
+    }
 
-        Ok(())
+    /// Spawns the task running a single WebSocket connection.
+    /// If the task stops with an error, the first such error is kept for `read` to return.
+    fn spawn_connection(
+        &mut self,
+        stream: TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>,
+        report_sender: mpsc::Sender<WebSocketReport>,
+        feed_ids: Vec<ID>,
+    ) {
+        let shutdown_receiver = self.shutdown_sender.subscribe();
+        let context = StreamContext {
+            config: self.config.clone(),
+            stats: self.stats.clone(),
+            water_mark: self.water_mark.clone(),
+            buffer_budget: self.buffer_budget.clone(),
+            event_sender: self.event_sender.clone(),
+        };
+        let terminal_error = self.terminal_error.clone();
+
+        let task = tokio::spawn(async move {
+            let result =
+                run_stream(stream, report_sender, shutdown_receiver, context, feed_ids).await;
+
+            if let Err(e) = result {
+                error!("Connection stopped: {}", e);
+                terminal_error.lock().await.get_or_insert(e);
+            }
+        });
+        self.tasks.push(task);
     }
 
     /// Reads the next available report on the Stream.
     /// Reads blocks until a report is received, the context is canceled or all underlying connections are in a error state.
+    /// In the latter case, the error that stopped the first connection is returned, e.g.
+    /// `StreamError::ClosedByServer`.
     ///
     /// # Returns
     ///
     /// * `WebSocketReport` - The next available report.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamError::StreamClosed` once all connections are closed, or if reports are
+    /// delivered to a handler registered with [`Stream::on_report`].
     pub async fn read(&mut self) -> Result<WebSocketReport, StreamError> {
-        self.report_receiver
+        let report = match self
+            .report_receiver
+            .as_mut()
+            .ok_or(StreamError::StreamClosed)?
             .recv()
             .await
-            .ok_or(StreamError::StreamClosed)
+        {
+            Some(report) => report,
+            None => {
+                let terminal_error = self.terminal_error.lock().await.take();
+                return Err(terminal_error.unwrap_or(StreamError::StreamClosed));
+            }
+        };
+
+        release_buffered(
+            &report,
+            &self.stats,
+            self.buffer_budget.as_deref(),
+            self.config.max_buffered_bytes,
+        );
+
+        Ok(report)
+    }
+
+    /// Like [`Stream::read`], but gives up waiting for a report after `timeout`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(WebSocketReport)` - The next available report.
+    /// * `None` - No report arrived within `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Stream::read`].
+    pub async fn read_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Option<WebSocketReport>, StreamError> {
+        match tokio::time::timeout(timeout, self.read()).await {
+            Ok(result) => result.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Delivers every accepted report to `handler` instead of [`Stream::read`].
+    ///
+    /// The handler runs on a spawned task until [`Stream::close`] is called or all connections
+    /// are closed; `close` waits for a call in progress to return. Once registered, `read`
+    /// returns `StreamError::StreamClosed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamError::ConnectionError` if a handler is already registered.
+    pub fn on_report<F>(&mut self, mut handler: F) -> Result<(), StreamError>
+    where
+        F: FnMut(WebSocketReport) + Send + 'static,
+    {
+        let mut report_receiver = self
+            .report_receiver
+            .take()
+            .ok_or_else(|| StreamError::ConnectionError("Report handler already set".into()))?;
+        let mut shutdown_receiver = self.shutdown_sender.subscribe();
+        let stats = self.stats.clone();
+        let buffer_budget = self.buffer_budget.clone();
+        let max_buffered_bytes = self.config.max_buffered_bytes;
+
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown_receiver.recv() => {
+                        debug!("Report handler received shutdown signal");
+                        break;
+                    }
+                    report = report_receiver.recv() => {
+                        let Some(report) = report else {
+                            break;
+                        };
+
+                        release_buffered(
+                            &report,
+                            &stats,
+                            buffer_budget.as_deref(),
+                            max_buffered_bytes,
+                        );
+                        handler(report);
+                    }
+                }
+            }
+        });
+        self.tasks.push(task);
+
+        Ok(())
+    }
+
+    /// Returns the observations timestamp of the latest report accepted for `feed_id`, the
+    /// watermark at or below which reports of the feed are filtered as duplicates.
+    /// `None` if no report was accepted for it since the Stream started or was last reset.
+    ///
+    /// Watermarks are kept across reconnects, including a full reconnect after every
+    /// connection dropped, so reports replayed by a fresh connection are still filtered.
+    pub async fn last_seen_timestamp(&self, feed_id: &ID) -> Option<usize> {
+        self.water_mark
+            .lock()
+            .await
+            .get(feed_id)
+            .map(|mark| mark.observations_timestamp)
+    }
+
+    /// Clears the watermarks of all feeds, so the next report received for each is accepted even
+    /// if it is not newer than the last one.
+    pub async fn reset_watermark(&self) {
+        self.water_mark.lock().await.clear();
+        info!("Watermarks reset.");
+    }
+
+    /// Subscribes to connection lifecycle events of the Stream, such as circuit breaker transitions.
+    /// Only events published after subscribing are received.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.event_sender.subscribe()
     }
 
     /// Closes the Stream.
     /// It is the caller's responsibility to call close when the stream is no longer needed.
+    /// Returns once all connections have stopped, aborting any still running after a timeout.
     pub async fn close(&mut self) -> Result<(), StreamError> {
         info!("Closing stream...");
 
@@ -232,12 +606,50 @@ impl Stream {
             debug!("Shutdown signal not sent (no active receivers). Stream may already be closed. Error received: {:?}", e);
         }
 
-        // Allow tasks to shut down gracefully
-        sleep(Duration::from_millis(100)).await;
+        // Connections waiting out a reconnect backoff, or for room in a full report channel,
+        // don't see the signal, so they are aborted once the timeout elapses
+        let deadline = Instant::now() + CLOSE_TIMEOUT;
+        for mut task in self.tasks.drain(..) {
+            if timeout_at(deadline, &mut task).await.is_err() {
+                warn!("Connection task did not stop in time, aborting it.");
+                task.abort();
+            }
+        }
 
         Ok(())
     }
 
+    /// Closes the Stream like [`Stream::close`], returning the reports that were received but not
+    /// read yet instead of dropping them.
+    ///
+    /// The connections stop accepting new reports first, so no report is accepted after the
+    /// returned ones. A report a connection was still waiting to buffer because the channel was
+    /// full is discarded. Returns no reports if they are delivered to a handler registered with
+    /// [`Stream::on_report`].
+    pub async fn close_drain(&mut self) -> Vec<WebSocketReport> {
+        // Errors only if no connection is running, in which case there is nothing to stop
+        let _ = self.shutdown_sender.send(());
+
+        let mut reports = Vec::new();
+        if let Some(receiver) = self.report_receiver.as_mut() {
+            receiver.close();
+
+            while let Some(report) = receiver.recv().await {
+                release_buffered(
+                    &report,
+                    &self.stats,
+                    self.buffer_budget.as_deref(),
+                    self.config.max_buffered_bytes,
+                );
+                reports.push(report);
+            }
+        }
+
+        let _ = self.close().await;
+
+        reports
+    }
+
     /// Returns basic stats about the Stream.
     ///
     /// # Returns
@@ -250,6 +662,9 @@ impl Stream {
     ///     * `full_reconnects` - Total number of full reconnects.
     ///     * `configured_connections` - Number of configured connections if in HA.
     ///     * `active_connections` - Current number of active connections.
+    ///     * `channel_highwater_events` - Total number of times the report channel crossed the high-water mark.
+    ///     * `circuit_state` - Current state of the circuit breaker.
+    ///     * `buffered_bytes` - Total size in bytes of reports received but not yet read.
     pub fn get_stats(&self) -> StatsSnapshot {
         let accepted = self.stats.accepted.load(Ordering::SeqCst);
         let deduplicated = self.stats.deduplicated.load(Ordering::SeqCst);
@@ -262,10 +677,138 @@ impl Stream {
             full_reconnects: self.stats.full_reconnects.load(Ordering::SeqCst),
             configured_connections: self.stats.configured_connections.load(Ordering::SeqCst),
             active_connections: self.stats.active_connections.load(Ordering::SeqCst),
+            channel_highwater_events: self.stats.channel_highwater_events.load(Ordering::SeqCst),
+            circuit_state: self.stats.circuit.state(),
+            buffered_bytes: self.stats.buffered_bytes.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Returns the connection health of the Stream.
+    ///
+    /// # Returns
+    ///
+    /// * `StreamHealth::Healthy` - Every configured connection is active, i.e. the single
+    ///   connection, or all origins in HA mode.
+    /// * `StreamHealth::Degraded` - In HA mode, some origins are reconnecting.
+    /// * `StreamHealth::Down` - No connection is active.
+    pub fn health(&self) -> StreamHealth {
+        StreamHealth::from_connections(
+            self.stats.active_connections.load(Ordering::SeqCst),
+            self.stats.configured_connections.load(Ordering::SeqCst),
+        )
+    }
+
+    /// Returns `true` if every configured connection of the Stream is active, e.g. for a
+    /// readiness probe. See `Stream::health` for a finer-grained status.
+    pub fn is_healthy(&self) -> bool {
+        self.health() == StreamHealth::Healthy
+    }
+
+    /// Returns a [`futures::Stream`] of reports read from the Stream, so the `StreamExt`
+    /// combinators (`filter`, `map`, `take`, ...) can be used on it.
+    ///
+    /// Each item is the result of [`Stream::read`]. The adapter ends once the Stream is closed;
+    /// any other error is yielded before it ends. Dropping the adapter does not lose reports
+    /// and leaves the Stream usable, e.g. to `close` it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use chainlink_data_streams_sdk::stream::{Stream, StreamError};
+    /// # use futures::StreamExt;
+    /// # async fn run(mut stream: Stream) -> Result<(), StreamError> {
+    /// let mut reports = stream.reports().take(10);
+    ///
+    /// while let Some(report) = reports.next().await {
+    ///     println!("{}", report?.report.observations_timestamp);
+    /// }
+    /// drop(reports);
+    ///
+    /// stream.close().await
+    /// # }
+    /// ```
+    pub fn reports(&mut self) -> ReportStream<'_> {
+        ReportStream {
+            stream: Some(self),
+            read: None,
+            terminated: false,
         }
     }
 }
 
+type PendingRead<'a> = Pin<
+    Box<dyn Future<Output = (&'a mut Stream, Result<WebSocketReport, StreamError>)> + Send + 'a>,
+>;
+
+/// [`futures::Stream`] adapter over a [`Stream`], returned by [`Stream::reports`].
+pub struct ReportStream<'a> {
+    /// The Stream, while no read is in flight
+    stream: Option<&'a mut Stream>,
+    /// The read in flight, which hands the Stream back once it completes
+    read: Option<PendingRead<'a>>,
+    terminated: bool,
+}
+
+impl<'a> futures::Stream for ReportStream<'a> {
+    type Item = Result<WebSocketReport, StreamError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        if self.read.is_none() {
+            let stream = self.stream.take().expect("stream is idle");
+            self.read = Some(Box::pin(async move {
+                let result = stream.read().await;
+                (stream, result)
+            }));
+        }
+
+        let (stream, result) = ready!(self
+            .read
+            .as_mut()
+            .expect("read is in flight")
+            .as_mut()
+            .poll(cx));
+        self.read = None;
+        self.stream = Some(stream);
+
+        match result {
+            Ok(report) => Poll::Ready(Some(Ok(report))),
+            Err(StreamError::StreamClosed) => {
+                self.terminated = true;
+                Poll::Ready(None)
+            }
+            Err(e) => {
+                self.terminated = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+}
+
+impl<'a> FusedStream for ReportStream<'a> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+/// Releases the buffer accounting held by a report once it leaves the report channel.
+fn release_buffered(
+    report: &WebSocketReport,
+    stats: &Stats,
+    buffer_budget: Option<&Semaphore>,
+    max_buffered_bytes: Option<usize>,
+) {
+    let size = buffered_size(report);
+    stats.buffered_bytes.fetch_sub(size, Ordering::SeqCst);
+
+    if let (Some(budget), Some(max_buffered_bytes)) = (buffer_budget, max_buffered_bytes) {
+        budget.add_permits(budget_permits(size, max_buffered_bytes) as usize);
+    }
+}
+
 /// Snapshot of statistics for external consumption.
 #[derive(Debug, Clone)]
 pub struct StatsSnapshot {
@@ -283,4 +826,80 @@ pub struct StatsSnapshot {
     pub configured_connections: usize,
     /// Current number of active connections
     pub active_connections: usize,
+    /// Total number of times the report channel crossed the high-water mark
+    pub channel_highwater_events: usize,
+    /// Current state of the circuit breaker
+    pub circuit_state: CircuitState,
+    /// Total size in bytes of reports received but not yet read
+    pub buffered_bytes: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_tungstenite::tungstenite::{error::ProtocolError, http::Response};
+
+    fn handshake_error(status: u16) -> StreamError {
+        let response = Response::builder().status(status).body(None).unwrap();
+        StreamError::from(WsError::Http(response))
+    }
+
+    #[test]
+    fn test_stream_health_from_connections() {
+        assert_eq!(StreamHealth::from_connections(1, 1), StreamHealth::Healthy);
+        assert_eq!(StreamHealth::from_connections(0, 1), StreamHealth::Down);
+        assert_eq!(StreamHealth::from_connections(3, 3), StreamHealth::Healthy);
+        assert_eq!(StreamHealth::from_connections(2, 3), StreamHealth::Degraded);
+        assert_eq!(StreamHealth::from_connections(0, 3), StreamHealth::Down);
+    }
+
+    #[test]
+    fn test_transport_errors_are_retryable() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+
+        assert!(StreamError::from(WsError::Io(io_error)).is_retryable());
+        assert!(StreamError::from(WsError::ConnectionClosed).is_retryable());
+        assert!(StreamError::from(WsError::Protocol(
+            ProtocolError::ResetWithoutClosingHandshake
+        ))
+        .is_retryable());
+        assert!(StreamError::ConnectionError("timed out".into()).is_retryable());
+    }
+
+    #[test]
+    fn test_handshake_rejection_is_not_retryable() {
+        assert!(!handshake_error(400).is_retryable());
+        assert!(!handshake_error(401).is_retryable());
+        assert!(!handshake_error(403).is_retryable());
+        assert!(handshake_error(500).is_retryable());
+        assert!(handshake_error(503).is_retryable());
+    }
+
+    #[test]
+    fn test_local_errors_are_not_retryable() {
+        let header_error = reqwest::header::HeaderValue::from_str("\n").unwrap_err();
+        let decode_error = serde_json::from_str::<WebSocketReport>("not json").unwrap_err();
+
+        assert!(
+            !StreamError::AuthError(crate::auth::HmacError::InvalidHeaderValue(header_error))
+                .is_retryable()
+        );
+        assert!(!StreamError::SerializationError(decode_error).is_retryable());
+        assert!(!StreamError::StreamClosed.is_retryable());
+    }
+
+    #[test]
+    fn test_reject_malformed_feed_id() {
+        let frame = |feed_id: &str| {
+            format!(
+                r#"{{"report":{{"feedID":"{}","validFromTimestamp":1,"observationsTimestamp":1,"fullReport":"0x"}}}}"#,
+                feed_id
+            )
+        };
+        let feed_id = "0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439";
+
+        assert!(serde_json::from_str::<WebSocketReport>(&frame(feed_id)).is_ok());
+        assert!(serde_json::from_str::<WebSocketReport>(&frame(&feed_id[2..])).is_err());
+        assert!(serde_json::from_str::<WebSocketReport>(&frame(&feed_id[..64])).is_err());
+    }
 }
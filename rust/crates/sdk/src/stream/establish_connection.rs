@@ -1,25 +1,34 @@
 use super::{Stats, StreamError, WebSocketConnection};
 
 use crate::{
-    auth::generate_auth_headers,
+    auth::{auth_timestamp, generate_auth_headers},
     config::{Config, WebSocketHighAvailability},
     endpoints::API_V1_WS,
-    stream::{DEFAULT_WS_CONNECT_TIMEOUT, MAX_WS_RECONNECT_INTERVAL, MIN_WS_RECONNECT_INTERVAL},
+    stream::DEFAULT_WS_CONNECT_TIMEOUT,
 };
 
 use chainlink_data_streams_report::feed_id::ID;
 
+use base64::{engine::general_purpose::STANDARD, Engine};
+use percent_encoding::percent_decode_str;
+use reqwest::Url;
+use socket2::{SockRef, TcpKeepalive};
 use std::{
     sync::{atomic::Ordering, Arc},
-    time::{SystemTime, UNIX_EPOCH},
+    time::Duration,
 };
 use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
     net::TcpStream,
-    time::{sleep, timeout},
+    time::{sleep, timeout, Instant},
 };
 use tokio_tungstenite::{
-    connect_async, tungstenite::client::IntoClientRequest, MaybeTlsStream,
-    WebSocketStream as TungsteniteWebSocketStream,
+    client_async_tls_with_config,
+    tungstenite::{
+        client::IntoClientRequest, error::UrlError, handshake::client::Request, http,
+        Error as WsError,
+    },
+    MaybeTlsStream, WebSocketStream as TungsteniteWebSocketStream,
 };
 use tracing::{error, info};
 
@@ -30,6 +39,100 @@ fn parse_origins(ws_url: &str) -> Vec<String> {
         .collect()
 }
 
+/// Longest proxy response head to a `CONNECT` request that is read before giving up.
+const MAX_PROXY_RESPONSE_HEAD: usize = 8 * 1024;
+
+/// Opens a tunnel to `host:port` through the HTTP proxy at `proxy` with a `CONNECT` request.
+///
+/// Credentials in the proxy URL are sent as `Proxy-Authorization: Basic`. A proxy answering with
+/// a non-`2xx` status fails with `WsError::Http`, so its status decides whether to retry.
+async fn connect_via_proxy(proxy: &str, host: &str, port: u16) -> Result<TcpStream, WsError> {
+    let proxy = Url::parse(proxy).map_err(|_| WsError::Url(UrlError::UnsupportedUrlScheme))?;
+    let proxy_host = proxy.host_str().ok_or(WsError::Url(UrlError::NoHostName))?;
+    let proxy_port = proxy.port_or_known_default().unwrap_or(80);
+
+    let mut stream = TcpStream::connect((proxy_host, proxy_port)).await?;
+
+    let mut connect_request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if !proxy.username().is_empty() {
+        let decode = |value: &str| percent_decode_str(value).decode_utf8_lossy().into_owned();
+        let credentials = format!(
+            "{}:{}",
+            decode(proxy.username()),
+            decode(proxy.password().unwrap_or_default())
+        );
+        connect_request.push_str(&format!(
+            "Proxy-Authorization: Basic {}\r\n",
+            STANDARD.encode(credentials)
+        ));
+    }
+    connect_request.push_str("\r\n");
+    stream.write_all(connect_request.as_bytes()).await?;
+
+    // Read one byte at a time, so nothing past the response head is taken from the tunnel
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_PROXY_RESPONSE_HEAD {
+            return Err(proxy_error(http::StatusCode::BAD_GATEWAY));
+        }
+        head.push(stream.read_u8().await?);
+    }
+
+    let status = String::from_utf8_lossy(&head)
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .and_then(|status| http::StatusCode::from_u16(status).ok())
+        .unwrap_or(http::StatusCode::BAD_GATEWAY);
+
+    if !status.is_success() {
+        return Err(proxy_error(status));
+    }
+
+    Ok(stream)
+}
+
+/// Reports a failed proxy handshake as an HTTP response with `status`.
+fn proxy_error(status: http::StatusCode) -> WsError {
+    let mut response = http::Response::new(None);
+    *response.status_mut() = status;
+    WsError::Http(response)
+}
+
+/// Opens the TCP connection for a WebSocket request, through `proxy` if one is given, enabling
+/// TCP keepalive if configured.
+async fn connect_tcp(
+    request: &Request,
+    tcp_keepalive: Option<Duration>,
+    proxy: Option<&str>,
+) -> Result<TcpStream, WsError> {
+    let uri = request.uri();
+    let host = uri.host().ok_or(WsError::Url(UrlError::NoHostName))?;
+    let port = uri
+        .port_u16()
+        .or_else(|| match uri.scheme_str() {
+            Some("wss") => Some(443),
+            Some("ws") => Some(80),
+            _ => None,
+        })
+        .ok_or(WsError::Url(UrlError::UnsupportedUrlScheme))?;
+
+    let stream = match proxy {
+        Some(proxy) => connect_via_proxy(proxy, host, port).await?,
+        None => TcpStream::connect(format!("{}:{}", host, port)).await?,
+    };
+
+    if let Some(idle) = tcp_keepalive {
+        SockRef::from(&stream).set_tcp_keepalive(&TcpKeepalive::new().with_time(idle))?;
+    }
+
+    Ok(stream)
+}
+
 async fn connect_to_origin(
     config: &Config,
     origin: &str,
@@ -43,10 +146,7 @@ async fn connect_to_origin(
     let body = b"";
     let client_id = &config.api_key;
     let user_secret = &config.api_secret;
-    let request_timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("System time error")
-        .as_millis();
+    let request_timestamp = auth_timestamp(config.auth_clock)?;
 
     let headers = generate_auth_headers(
         method,
@@ -63,12 +163,16 @@ async fn connect_to_origin(
     })?;
     request.headers_mut().extend(headers);
 
-    let connect_future = connect_async(request);
+    let connect_future = async {
+        let tcp_stream =
+            connect_tcp(&request, config.tcp_keepalive, config.proxy.as_deref()).await?;
+        client_async_tls_with_config(request, tcp_stream, None, None).await
+    };
 
     let (ws_stream, ws_response) = timeout(DEFAULT_WS_CONNECT_TIMEOUT, connect_future)
         .await
         .map_err(|_| StreamError::ConnectionError("WebSocket connection timed out".to_string()))?
-        .map_err(|e| StreamError::ConnectionError(format!("Failed to connect: {}", e)))?;
+        .map_err(StreamError::from)?;
 
     info!("Connected to WebSocket: {:#?}", ws_response);
 
@@ -114,10 +218,24 @@ pub(crate) async fn connect(
         stats.configured_connections.fetch_add(1, Ordering::SeqCst);
         stats.active_connections.fetch_add(1, Ordering::SeqCst);
 
-        Ok(WebSocketConnection::Single(stream))
+        Ok(WebSocketConnection::Single(Box::new(stream)))
     }
 }
 
+/// Makes a single connection attempt to the first origin, without retrying.
+pub(crate) async fn try_to_connect_once(
+    stats: Arc<Stats>,
+    config: &Config,
+    feed_ids: &[ID],
+) -> Result<TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>, StreamError> {
+    let origin = config.ws_url.split(',').next().unwrap();
+    info!("Attempting to connect to origin: {}", origin);
+
+    let new_stream = connect_to_origin(config, origin, feed_ids).await?;
+    stats.active_connections.fetch_add(1, Ordering::SeqCst);
+    Ok(new_stream)
+}
+
 pub(crate) async fn try_to_reconnect(
     stats: Arc<Stats>,
     config: &Config,
@@ -126,7 +244,8 @@ pub(crate) async fn try_to_reconnect(
     let mut reconnect_attempts = 0;
     let max_reconnect_attempts = config.ws_max_reconnect;
     let origin = config.ws_url.split(',').next().unwrap();
-    let mut backoff = MIN_WS_RECONNECT_INTERVAL;
+    let mut backoff = config.ws_min_reconnect_interval;
+    let started_at = Instant::now();
 
     loop {
         info!("Attempting to reconnect to origin: {}", origin);
@@ -142,6 +261,11 @@ pub(crate) async fn try_to_reconnect(
                     reconnect_attempts, e
                 );
 
+                if !e.is_retryable() {
+                    error!("Reconnection error is not retryable. Exiting.");
+                    return Err(e);
+                }
+
                 if reconnect_attempts >= max_reconnect_attempts {
                     error!("Max reconnect attempts reached. Exiting.");
                     return Err(StreamError::ConnectionError(
@@ -149,11 +273,176 @@ pub(crate) async fn try_to_reconnect(
                     ));
                 }
 
+                if let Some(deadline) = config.ws_reconnect_deadline {
+                    if started_at.elapsed() + backoff > deadline {
+                        error!("Max reconnect deadline reached. Exiting.");
+                        return Err(StreamError::ConnectionError(format!(
+                            "Max reconnect deadline of {:?} reached",
+                            deadline
+                        )));
+                    }
+                }
+
                 error!("Retrying in {:?}.", backoff);
 
                 sleep(backoff).await;
-                backoff = (backoff * 2).min(MAX_WS_RECONNECT_INTERVAL);
+                backoff = (backoff * 2).min(config.ws_max_reconnect_interval);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_connect_tcp_sets_keepalive() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let request = format!("ws://{}", address).into_client_request().unwrap();
+
+        let stream = connect_tcp(&request, Some(Duration::from_secs(30)), None)
+            .await
+            .unwrap();
+        assert!(SockRef::from(&stream).keepalive().unwrap());
+
+        let stream = connect_tcp(&request, None, None).await.unwrap();
+        assert!(!SockRef::from(&stream).keepalive().unwrap());
+    }
+
+    /// Starts a proxy answering one `CONNECT` request with `response`, then echoing the tunnel.
+    /// Returns the proxy URL and a receiver for the request head it got.
+    async fn mock_proxy(
+        response: impl Into<String>,
+    ) -> (String, tokio::sync::oneshot::Receiver<String>) {
+        let response = response.into();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (head_sender, head_receiver) = tokio::sync::oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(socket.read_u8().await.unwrap());
+            }
+            head_sender.send(String::from_utf8(head).unwrap()).unwrap();
+
+            socket.write_all(response.as_bytes()).await.unwrap();
+            let (mut reader, mut writer) = socket.split();
+            let _ = tokio::io::copy(&mut reader, &mut writer).await;
+        });
+
+        (format!("http://user:p%40ss@{}", address), head_receiver)
+    }
+
+    #[tokio::test]
+    async fn test_connect_via_proxy() {
+        let (proxy, head) = mock_proxy("HTTP/1.1 200 Connection established\r\n\r\n").await;
+
+        let mut stream = connect_via_proxy(&proxy, "streams.example.com", 443)
+            .await
+            .unwrap();
+
+        let head = head.await.unwrap();
+        assert!(head.starts_with("CONNECT streams.example.com:443 HTTP/1.1\r\n"));
+        assert!(head.contains(&format!(
+            "Proxy-Authorization: Basic {}\r\n",
+            STANDARD.encode("user:p@ss")
+        )));
+
+        // Bytes written after the handshake go through the tunnel
+        stream.write_all(b"ping").await.unwrap();
+        let mut echoed = [0u8; 4];
+        stream.read_exact(&mut echoed).await.unwrap();
+        assert_eq!(&echoed, b"ping");
+    }
+
+    #[tokio::test]
+    async fn test_connect_via_proxy_rejected() {
+        let (proxy, _head) = mock_proxy("HTTP/1.1 407 Proxy Authentication Required\r\n\r\n").await;
+
+        let err = connect_via_proxy(&proxy, "streams.example.com", 443)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            &err,
+            WsError::Http(response) if response.status() == http::StatusCode::PROXY_AUTHENTICATION_REQUIRED
+        ));
+        assert!(!StreamError::from(err).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_connect_via_proxy_response_head_too_large() {
+        let response = format!(
+            "HTTP/1.1 200 Connection established\r\nX-Padding: {}",
+            "a".repeat(MAX_PROXY_RESPONSE_HEAD)
+        );
+        let (proxy, _head) = mock_proxy(response).await;
+
+        let err = connect_via_proxy(&proxy, "streams.example.com", 443)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            &err,
+            WsError::Http(response) if response.status() == http::StatusCode::BAD_GATEWAY
+        ));
+    }
+
+    /// Returns an origin that refuses connections.
+    async fn closed_origin() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        format!("ws://{}", listener.local_addr().unwrap())
+    }
+
+    fn reconnect_config(ws_url: String) -> crate::config::ConfigBuilder {
+        Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            "http://localhost".to_string(),
+            ws_url,
+        )
+        .with_ws_max_reconnect(10)
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_interval() {
+        let config = reconnect_config(closed_origin().await)
+            .with_ws_reconnect_interval(Duration::from_millis(10), Duration::from_millis(20))
+            .build()
+            .unwrap();
+
+        // The default intervals would take over a minute to exhaust 10 attempts
+        let started_at = Instant::now();
+        let result = try_to_reconnect(Arc::new(Stats::default()), &config, &[]).await;
+
+        assert!(started_at.elapsed() < Duration::from_secs(2));
+        assert!(matches!(
+            result,
+            Err(StreamError::ConnectionError(message)) if message.contains("attempts")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_deadline() {
+        let config = reconnect_config(closed_origin().await)
+            .with_ws_reconnect_deadline(Duration::from_secs(2))
+            .build()
+            .unwrap();
+
+        // Attempts at 0s and 1s fail, and the next one, at 3s, would be past the deadline
+        let started_at = Instant::now();
+        let result = try_to_reconnect(Arc::new(Stats::default()), &config, &[]).await;
+
+        assert!(started_at.elapsed() < Duration::from_secs(2));
+        assert!(matches!(
+            result,
+            Err(StreamError::ConnectionError(message)) if message.contains("deadline")
+        ));
+    }
+}
@@ -1,67 +1,320 @@
-use super::{Stats, StreamError, WebSocketReport};
+use super::{CircuitState, ConnectionEvent, Stats, StreamError, StreamEvent, WebSocketReport};
 
-use crate::{config::Config, stream::establish_connection::try_to_reconnect};
+use crate::{
+    config::{CircuitBreaker, Config},
+    stream::establish_connection::{try_to_connect_once, try_to_reconnect},
+};
 
 use chainlink_data_streams_report::feed_id::ID;
+use chainlink_data_streams_report::report::compress::{
+    decompress_report_raw, COMPRESSION_FORMAT_VERSION,
+};
 
 use futures::SinkExt;
 use futures_util::StreamExt;
 use std::{
     collections::HashMap,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 use tokio::{
     net::TcpStream,
-    sync::{broadcast, mpsc, Mutex},
+    sync::{broadcast, mpsc, Mutex, Semaphore},
+    time::{interval, sleep, Interval},
 };
 use tokio_tungstenite::{
     tungstenite::Message, MaybeTlsStream, WebSocketStream as TungsteniteWebSocketStream,
 };
 use tracing::{error, info, warn};
 
+/// The latest report accepted for a feed, and when it was accepted.
+///
+/// Watermarks are owned by the Stream, not by its connections, so they survive partial and full
+/// reconnects; only `Stream::reset_watermark` clears them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct WaterMark {
+    pub(crate) observations_timestamp: usize,
+    accepted_at: Instant,
+}
+
+impl WaterMark {
+    /// Returns `true` if a report with `observations_timestamp` duplicates one already accepted.
+    /// Older timestamps are always duplicates. With a `dedup_window`, a timestamp equal to the
+    /// watermark is accepted again once the window has elapsed.
+    ///
+    /// An equal timestamp counts as a duplicate: with high availability, every origin delivers the
+    /// same report, and only the first copy should be accepted.
+    fn is_duplicate(
+        &self,
+        observations_timestamp: usize,
+        dedup_window: Option<Duration>,
+        now: Instant,
+    ) -> bool {
+        let within_window =
+            dedup_window.map_or(true, |window| now.duration_since(self.accepted_at) < window);
+
+        observations_timestamp < self.observations_timestamp
+            || (within_window && observations_timestamp == self.observations_timestamp)
+    }
+
+    /// Records an accepted report. The watermark never moves backwards.
+    fn advance(&mut self, observations_timestamp: usize, now: Instant) {
+        self.observations_timestamp = self.observations_timestamp.max(observations_timestamp);
+        self.accepted_at = now;
+    }
+}
+
+/// Undoes the watermark advance made at `accepted_at` for a report that could not be delivered,
+/// restoring `previous`. Left alone if another report has been accepted for the feed since.
+fn restore_water_mark(
+    water_mark: &mut HashMap<ID, WaterMark>,
+    feed_id: ID,
+    previous: Option<WaterMark>,
+    accepted_at: Instant,
+) {
+    if water_mark
+        .get(&feed_id)
+        .map_or(true, |mark| mark.accepted_at != accepted_at)
+    {
+        return;
+    }
+
+    match previous {
+        Some(previous) => {
+            water_mark.insert(feed_id, previous);
+        }
+        None => {
+            water_mark.remove(&feed_id);
+        }
+    }
+}
+
+/// Bytes a report holds while it is buffered in the report channel.
+pub(crate) fn buffered_size(report: &WebSocketReport) -> usize {
+    report.report.full_report.len() + report.raw.as_ref().map_or(0, Vec::len)
+}
+
+/// Permits a report of `size` bytes takes from the buffer budget.
+/// Capped at the budget, so a report larger than the budget passes once the buffer is empty.
+pub(crate) fn budget_permits(size: usize, max_buffered_bytes: usize) -> u32 {
+    size.min(max_buffered_bytes).min(u32::MAX as usize) as u32
+}
+
+const CIRCUIT_CLOSED: u8 = 0;
+const CIRCUIT_OPEN: u8 = 1;
+const CIRCUIT_HALF_OPEN: u8 = 2;
+
+/// Circuit breaker state shared by all connections of a Stream.
+#[derive(Default)]
+pub(crate) struct CircuitBreakerState {
+    state: AtomicU8,
+    consecutive_full_reconnects: AtomicUsize,
+}
+
+impl CircuitBreakerState {
+    pub(crate) fn state(&self) -> CircuitState {
+        match self.state.load(Ordering::SeqCst) {
+            CIRCUIT_OPEN => CircuitState::Open,
+            CIRCUIT_HALF_OPEN => CircuitState::HalfOpen,
+            _ => CircuitState::Closed,
+        }
+    }
+
+    /// Counts a full reconnect and returns `true` if the circuit should open.
+    /// A full reconnect while half-open means the probe connection dropped before delivering a report.
+    fn record_full_reconnect(&self, failure_threshold: usize) -> bool {
+        let failures = self
+            .consecutive_full_reconnects
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+
+        self.state() == CircuitState::HalfOpen || failures >= failure_threshold
+    }
+
+    /// Opens the circuit. Returns `true` if it was not open already.
+    fn open(&self) -> bool {
+        self.state.swap(CIRCUIT_OPEN, Ordering::SeqCst) != CIRCUIT_OPEN
+    }
+
+    /// Moves the circuit to half-open. Returns `true` if it was not half-open already.
+    fn half_open(&self) -> bool {
+        self.state.swap(CIRCUIT_HALF_OPEN, Ordering::SeqCst) != CIRCUIT_HALF_OPEN
+    }
+
+    /// Resets the failure count after a report is accepted and closes the circuit.
+    /// Returns `true` if the circuit was not closed already.
+    fn record_accepted(&self) -> bool {
+        self.consecutive_full_reconnects.store(0, Ordering::SeqCst);
+        self.state.swap(CIRCUIT_CLOSED, Ordering::SeqCst) != CIRCUIT_CLOSED
+    }
+}
+
+/// Client-initiated pings that detect a connection the server went silent on.
+struct KeepAlive {
+    timer: Option<Interval>,
+    awaiting_pong: bool,
+}
+
+impl KeepAlive {
+    fn new(ping_interval: Option<Duration>) -> Self {
+        let timer = ping_interval.map(|period| {
+            let mut timer = interval(period);
+            // The first tick would complete immediately, wait a full period instead
+            timer.reset();
+            timer
+        });
+
+        KeepAlive {
+            timer,
+            awaiting_pong: false,
+        }
+    }
+
+    /// Records that the connection is alive and restarts the idle period.
+    fn reset(&mut self) {
+        self.awaiting_pong = false;
+        if let Some(timer) = self.timer.as_mut() {
+            timer.reset();
+        }
+    }
+
+    /// Completes once the connection has been idle for the ping interval. Returns `true` if a
+    /// ping is due, or `false` if the previous ping went unanswered. Never completes when pings are
+    /// disabled.
+    async fn tick(&mut self) -> bool {
+        match self.timer.as_mut() {
+            Some(timer) => {
+                timer.tick().await;
+            }
+            None => std::future::pending().await,
+        }
+
+        !std::mem::replace(&mut self.awaiting_pong, true)
+    }
+}
+
+fn publish_event(event_sender: &broadcast::Sender<ConnectionEvent>, event: ConnectionEvent) {
+    // Sending fails only when nobody subscribed, which is fine
+    let _ = event_sender.send(event);
+}
+
+fn call_event_hook(config: &Config, event: StreamEvent) {
+    if let Some(hook) = config.stream_event_hook {
+        hook(event);
+    }
+}
+
+/// State a Stream shares with each of its connections.
+pub(crate) struct StreamContext {
+    pub(crate) config: Config,
+    pub(crate) stats: Arc<Stats>,
+    pub(crate) water_mark: Arc<Mutex<HashMap<ID, WaterMark>>>,
+    pub(crate) buffer_budget: Option<Arc<Semaphore>>,
+    pub(crate) event_sender: broadcast::Sender<ConnectionEvent>,
+}
+
 pub(crate) async fn run_stream(
     mut stream: TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>,
     report_sender: mpsc::Sender<WebSocketReport>,
     mut shutdown_receiver: broadcast::Receiver<()>,
-    stats: Arc<Stats>,
-    water_mark: Arc<Mutex<HashMap<String, usize>>>,
-    config: Config,
+    context: StreamContext,
     feed_ids: Vec<ID>,
 ) -> Result<(), StreamError> {
+    let StreamContext {
+        config,
+        stats,
+        water_mark,
+        buffer_budget,
+        event_sender,
+    } = context;
     let shutdown_flag = Arc::new(AtomicBool::new(false));
+    let mut keep_alive = KeepAlive::new(config.ws_ping_interval);
 
     loop {
         tokio::select! {
             message = stream.next() => {
                 match message {
                     Some(Ok(msg)) => {
+                        keep_alive.reset();
+
                         match msg {
-                            Message::Text(text) => {
-                                info!("Received text message: {}", text);
-                            }
-                            Message::Binary(data) => {
-                                info!("Received new report from Data Streams Endpoint.");
-                                if let Ok(report) = serde_json::from_slice::<WebSocketReport>(&data) {
-                                    let feed_id = report.report.feed_id.to_hex_string();
+                            msg @ (Message::Text(_) | Message::Binary(_)) => {
+                                if let Some((mut report, data)) = parse_report(msg, config.ws_compressed_frames) {
+                                    info!("Received new report from Data Streams Endpoint.");
+                                    let feed_id = report.report.feed_id;
                                     let observations_timestamp = report.report.observations_timestamp;
 
-                                    if water_mark.lock().await.contains_key(&feed_id) && water_mark.lock().await[&feed_id] >= observations_timestamp {
+                                    if config.retain_raw {
+                                        report.raw = Some(data);
+                                    }
+
+                                    // Wait for room in the buffer before the duplicate check, so connections
+                                    // held back together don't all pass it with the same report
+                                    let size = buffered_size(&report);
+                                    let permits = match (&buffer_budget, config.max_buffered_bytes) {
+                                        (Some(budget), Some(max_buffered_bytes)) => {
+                                            let permits = budget_permits(size, max_buffered_bytes);
+                                            budget.acquire_many(permits).await.map_err(|e| {
+                                                StreamError::ConnectionError(format!("Failed to reserve buffer: {}", e))
+                                            })?.forget();
+                                            Some((budget, permits))
+                                        }
+                                        _ => None,
+                                    };
+
+                                    // Check and advance the watermark in one lock section, so connections
+                                    // delivering the same report concurrently can't both accept it
+                                    let now = Instant::now();
+                                    let (is_duplicate, previous_mark) = {
+                                        let mut water_mark = water_mark.lock().await;
+                                        let previous_mark = water_mark.get(&feed_id).copied();
+                                        let is_duplicate = previous_mark.is_some_and(|mark| {
+                                            mark.is_duplicate(observations_timestamp, config.dedup_window, now)
+                                        });
+
+                                        if !is_duplicate {
+                                            water_mark
+                                                .entry(feed_id)
+                                                .and_modify(|mark| mark.advance(observations_timestamp, now))
+                                                .or_insert(WaterMark {
+                                                    observations_timestamp,
+                                                    accepted_at: now,
+                                                });
+                                        }
+
+                                        (is_duplicate, previous_mark)
+                                    };
+
+                                    if is_duplicate {
+                                        if let Some((budget, permits)) = permits {
+                                            budget.add_permits(permits as usize);
+                                        }
                                         stats.deduplicated.fetch_add(1, Ordering::SeqCst);
+                                        call_event_hook(&config, StreamEvent::ReportDeduplicated { feed_id });
                                         continue;
                                     }
 
-                                    report_sender.send(report).await.map_err(|e| {
-                                        StreamError::ConnectionError(format!("Failed to send report: {}", e))
-                                    })?;
+                                    // Counted before sending, so a concurrent read never takes the count below zero
+                                    stats.buffered_bytes.fetch_add(size, Ordering::SeqCst);
+
+                                    if let Err(e) = report_sender.send(report).await {
+                                        // Not delivered, so the report must not count as accepted
+                                        restore_water_mark(&mut *water_mark.lock().await, feed_id, previous_mark, now);
+                                        return Err(StreamError::ConnectionError(format!("Failed to send report: {}", e)));
+                                    }
 
-                                    water_mark.lock().await.insert(feed_id, observations_timestamp);
                                     stats.accepted.fetch_add(1, Ordering::SeqCst);
+                                    call_event_hook(&config, StreamEvent::ReportAccepted { feed_id });
 
-                                } else {
-                                    error!("Failed to parse binary message.");
+                                    if stats.circuit.record_accepted() {
+                                        info!("Circuit breaker closed.");
+                                        publish_event(&event_sender, ConnectionEvent::CircuitClosed);
+                                    }
+
+                                    check_channel_highwater(&report_sender, &stats, config.channel_highwater_warn);
                                 }
                             }
                             Message::Ping(payload) => {
@@ -78,10 +331,21 @@ pub(crate) async fn run_stream(
                             Message::Close(close_frame) => {
                                 if let Some(cf) = close_frame {
                                     info!("Connection closed: code={}, reason={}", cf.code, cf.reason);
+
+                                    let code = u16::from(cf.code);
+                                    if is_fatal_close_code(code) {
+                                        error!("Close code {} is not recoverable. Not reconnecting.", code);
+                                        stats.active_connections.fetch_sub(1, Ordering::SeqCst);
+                                        call_event_hook(&config, StreamEvent::ConnectionClosed);
+                                        return Err(StreamError::ClosedByServer {
+                                            code,
+                                            reason: cf.reason.into_owned(),
+                                        });
+                                    }
                                 } else {
                                     info!("Connection closed");
                                 }
-                                stats.active_connections.fetch_sub(1, Ordering::SeqCst);
+                                // The stream ends right after, which counts the connection down and reconnects
                             }
                             _ => {
                                 warn!("Received unhandled message.");
@@ -91,31 +355,52 @@ pub(crate) async fn run_stream(
                     Some(Err(e)) => {
                         error!("Error receiving message: {:?}", e);
                         stats.active_connections.fetch_sub(1, Ordering::SeqCst);
+                        call_event_hook(&config, StreamEvent::ConnectionClosed);
 
-                        stream = handle_reconnection(stats.clone(), &config, &feed_ids).await?;
+                        stream = handle_reconnection(stats.clone(), &config, &feed_ids, &event_sender).await?;
+                        keep_alive.reset();
                     }
                     None => {
                         info!("WebSocket stream closed.");
                         stats.active_connections.fetch_sub(1, Ordering::SeqCst);
+                        call_event_hook(&config, StreamEvent::ConnectionClosed);
 
                         if shutdown_flag.load(Ordering::SeqCst) {
                             info!("Stream closed gracefully after shutdown signal.");
                             return Ok(());
                         } else {
-                            stream = handle_reconnection(stats.clone(), &config, &feed_ids).await?;
+                            stream = handle_reconnection(stats.clone(), &config, &feed_ids, &event_sender).await?;
+                            keep_alive.reset();
                         }
                     }
                 }
             }
+            ping_due = keep_alive.tick() => {
+                if ping_due {
+                    match stream.send(Message::Ping(Vec::new())).await {
+                        Ok(()) => continue,
+                        Err(e) => error!("Failed to send ping: {:?}", e),
+                    }
+                } else {
+                    warn!("No response to ping, treating the connection as dead.");
+                }
+
+                stats.active_connections.fetch_sub(1, Ordering::SeqCst);
+                call_event_hook(&config, StreamEvent::ConnectionClosed);
+
+                stream = handle_reconnection(stats.clone(), &config, &feed_ids, &event_sender).await?;
+                keep_alive.reset();
+            }
             _ = shutdown_receiver.recv() => {
                 // Received shutdown signal
                 shutdown_flag.store(true, Ordering::SeqCst);
 
                 if let Err(e) = stream.close(None).await {
                     error!("Error closing stream: {:?}", e);
-                    return Err(StreamError::WebSocketError(e));
+                    return Err(StreamError::from(e));
                 }
                 stats.active_connections.fetch_sub(1, Ordering::SeqCst);
+                call_event_hook(&config, StreamEvent::ConnectionClosed);
                 info!("Stream closed gracefully after shutdown signal.");
                 return Ok(());
             }
@@ -123,17 +408,375 @@ pub(crate) async fn run_stream(
     }
 }
 
+/// Parses a text or binary frame as a report, returning it with the frame's payload.
+///
+/// Text frames that aren't a report are logged as plain server messages.
+fn parse_report(msg: Message, compressed_frames: bool) -> Option<(WebSocketReport, Vec<u8>)> {
+    match msg {
+        Message::Text(text) => match serde_json::from_str(&text) {
+            Ok(report) => Some((report, text.into_bytes())),
+            Err(_) => {
+                info!("Received text message: {}", text);
+                None
+            }
+        },
+        Message::Binary(data) => match parse_binary_report(&data, compressed_frames) {
+            Some(report) => Some((report, data)),
+            None => {
+                error!("Failed to parse binary message.");
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Parses a binary frame as a report.
+///
+/// With `compressed_frames` (see `Config::ws_compressed_frames`), frames starting with
+/// `COMPRESSION_FORMAT_VERSION` are decompressed first. Any other frame is parsed as plain JSON,
+/// which always starts with `{` or whitespace, so the two can't be confused.
+fn parse_binary_report(data: &[u8], compressed_frames: bool) -> Option<WebSocketReport> {
+    if compressed_frames && data.first() == Some(&COMPRESSION_FORMAT_VERSION) {
+        let decompressed = match decompress_report_raw(data) {
+            Ok(decompressed) => decompressed,
+            Err(e) => {
+                error!("Failed to decompress binary message: {:?}", e);
+                return None;
+            }
+        };
+        return serde_json::from_slice(&decompressed).ok();
+    }
+
+    serde_json::from_slice(data).ok()
+}
+
+/// Returns `true` for close codes that reconnecting would not resolve: policy violations such as
+/// rejected credentials (1008), server errors (1011) and application-defined codes (4000-4999).
+fn is_fatal_close_code(code: u16) -> bool {
+    matches!(code, 1008 | 1011 | 4000..=4999)
+}
+
+/// Emits a warning and counts an event when the report channel fills past the configured fraction
+/// of its capacity. Fires once per crossing and re-arms after the consumer drains the channel.
+fn check_channel_highwater(
+    report_sender: &mpsc::Sender<WebSocketReport>,
+    stats: &Stats,
+    highwater_fraction: f32,
+) {
+    let max_capacity = report_sender.max_capacity();
+    let queued = max_capacity - report_sender.capacity();
+
+    if queued as f32 >= highwater_fraction * max_capacity as f32 {
+        if !stats.channel_above_highwater.swap(true, Ordering::SeqCst) {
+            stats
+                .channel_highwater_events
+                .fetch_add(1, Ordering::SeqCst);
+            warn!(
+                "Report channel is {}/{} full. The consumer is falling behind.",
+                queued, max_capacity
+            );
+        }
+    } else {
+        stats.channel_above_highwater.store(false, Ordering::SeqCst);
+    }
+}
+
 async fn handle_reconnection(
     stats: Arc<Stats>,
     config: &Config,
     feed_ids: &[ID],
+    event_sender: &broadcast::Sender<ConnectionEvent>,
 ) -> Result<TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>, StreamError> {
     if stats.active_connections.load(Ordering::SeqCst) == 0 {
         stats.full_reconnects.fetch_add(1, Ordering::SeqCst);
+        call_event_hook(config, StreamEvent::FullReconnect);
+
+        if let Some(breaker) = config.circuit_breaker {
+            if stats
+                .circuit
+                .record_full_reconnect(breaker.failure_threshold)
+            {
+                return probe_after_cooldown(stats, config, feed_ids, event_sender, breaker).await;
+            }
+        }
     } else {
         stats.partial_reconnects.fetch_add(1, Ordering::SeqCst);
+        call_event_hook(config, StreamEvent::PartialReconnect);
     }
 
     let new_stream = try_to_reconnect(stats.clone(), config, feed_ids).await?;
     Ok(new_stream)
 }
+
+/// Holds off reconnecting while the circuit is open, then probes with a single connection attempt.
+/// A failed probe reopens the circuit for another cooldown.
+async fn probe_after_cooldown(
+    stats: Arc<Stats>,
+    config: &Config,
+    feed_ids: &[ID],
+    event_sender: &broadcast::Sender<ConnectionEvent>,
+    breaker: CircuitBreaker,
+) -> Result<TungsteniteWebSocketStream<MaybeTlsStream<TcpStream>>, StreamError> {
+    loop {
+        if stats.circuit.open() {
+            warn!(
+                "Circuit breaker opened. Pausing reconnects for {:?}.",
+                breaker.cooldown
+            );
+            publish_event(event_sender, ConnectionEvent::CircuitOpen);
+        }
+
+        sleep(breaker.cooldown).await;
+
+        if stats.circuit.half_open() {
+            info!("Circuit breaker half-open. Probing connection.");
+            publish_event(event_sender, ConnectionEvent::CircuitHalfOpen);
+        }
+
+        match try_to_connect_once(stats.clone(), config, feed_ids).await {
+            Ok(new_stream) => return Ok(new_stream),
+            Err(e) if !e.is_retryable() => {
+                error!("Probe connection error is not retryable. Exiting.");
+                return Err(e);
+            }
+            Err(e) => {
+                error!("Probe connection failed: {:?}.", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chainlink_data_streams_report::report::compress::compress_report_raw;
+
+    #[test]
+    fn test_is_fatal_close_code() {
+        for code in [1008, 1011, 4000, 4001, 4999] {
+            assert!(is_fatal_close_code(code), "{} should be fatal", code);
+        }
+        for code in [1000, 1001, 1006, 1012, 3000, 5000] {
+            assert!(!is_fatal_close_code(code), "{} should be transient", code);
+        }
+    }
+
+    #[test]
+    fn test_parse_binary_report() {
+        let json = br#"{"report":{"feedID":"0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439","validFromTimestamp":1,"observationsTimestamp":1,"fullReport":"0x"}}"#;
+        let expected: WebSocketReport = serde_json::from_slice(json).unwrap();
+
+        assert_eq!(parse_binary_report(json, false), Some(expected.clone()));
+        assert_eq!(parse_binary_report(json, true), Some(expected.clone()));
+
+        let compressed = compress_report_raw(json).unwrap();
+        assert_eq!(parse_binary_report(&compressed, true), Some(expected));
+
+        // Compressed frames are only decompressed when enabled
+        assert_eq!(parse_binary_report(&compressed, false), None);
+
+        // A corrupt compressed frame is dropped, not misread as JSON
+        assert_eq!(
+            parse_binary_report(&compressed[..compressed.len() / 2], true),
+            None
+        );
+        assert_eq!(parse_binary_report(b"not a report", true), None);
+    }
+
+    #[test]
+    fn test_parse_report_text_frame() {
+        let json = r#"{"report":{"feedID":"0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439","validFromTimestamp":1,"observationsTimestamp":1,"fullReport":"0x"}}"#;
+        let expected: WebSocketReport = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            parse_report(Message::Text(json.to_string()), false),
+            Some((expected, json.as_bytes().to_vec()))
+        );
+        assert_eq!(
+            parse_report(Message::Text("welcome".to_string()), false),
+            None
+        );
+        assert_eq!(parse_report(Message::Pong(vec![]), false), None);
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive() {
+        let mut keep_alive = KeepAlive::new(Some(Duration::from_millis(20)));
+        assert!(keep_alive.tick().await);
+        assert!(!keep_alive.tick().await);
+
+        keep_alive.reset();
+        assert!(keep_alive.tick().await);
+
+        let mut disabled = KeepAlive::new(None);
+        let tick = tokio::time::timeout(Duration::from_millis(50), disabled.tick()).await;
+        assert!(tick.is_err());
+    }
+
+    #[test]
+    fn test_water_mark_is_duplicate() {
+        let accepted_at = Instant::now();
+        let mark = WaterMark {
+            observations_timestamp: 100,
+            accepted_at,
+        };
+
+        let later = accepted_at + Duration::from_secs(3600);
+        assert!(mark.is_duplicate(100, None, later));
+        assert!(mark.is_duplicate(99, None, later));
+        assert!(!mark.is_duplicate(101, None, later));
+    }
+
+    #[test]
+    fn test_water_mark_dedup_window() {
+        let accepted_at = Instant::now();
+        let mark = WaterMark {
+            observations_timestamp: 100,
+            accepted_at,
+        };
+        let window = Some(Duration::from_secs(60));
+
+        // Inside the window, equal and older timestamps are still suppressed
+        let inside = accepted_at + Duration::from_secs(59);
+        assert!(mark.is_duplicate(100, window, inside));
+        assert!(mark.is_duplicate(99, window, inside));
+        assert!(!mark.is_duplicate(101, window, inside));
+
+        // Once the window has elapsed, an equal timestamp is accepted again, but an older one is not
+        let boundary = accepted_at + Duration::from_secs(60);
+        assert!(!mark.is_duplicate(100, window, boundary));
+        assert!(!mark.is_duplicate(100, window, boundary + Duration::from_secs(1)));
+        assert!(mark.is_duplicate(99, window, boundary));
+        assert!(mark.is_duplicate(0, window, boundary + Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn test_water_mark_never_moves_backwards() {
+        let accepted_at = Instant::now();
+        let mut mark = WaterMark {
+            observations_timestamp: 100,
+            accepted_at,
+        };
+
+        let later = accepted_at + Duration::from_secs(120);
+        mark.advance(90, later);
+        assert_eq!(mark.observations_timestamp, 100);
+        assert_eq!(mark.accepted_at, later);
+
+        mark.advance(101, later);
+        assert_eq!(mark.observations_timestamp, 101);
+    }
+
+    #[test]
+    fn test_restore_water_mark() {
+        let feed_id =
+            ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439")
+                .unwrap();
+        let previous = WaterMark {
+            observations_timestamp: 100,
+            accepted_at: Instant::now(),
+        };
+        let accepted_at = previous.accepted_at + Duration::from_secs(1);
+        let mut advanced = previous;
+        advanced.advance(101, accepted_at);
+
+        let mut water_mark = HashMap::from([(feed_id, advanced)]);
+        restore_water_mark(&mut water_mark, feed_id, Some(previous), accepted_at);
+        assert_eq!(water_mark.get(&feed_id), Some(&previous));
+
+        // A first report for the feed leaves no watermark behind
+        let mut water_mark = HashMap::from([(feed_id, advanced)]);
+        restore_water_mark(&mut water_mark, feed_id, None, accepted_at);
+        assert!(water_mark.is_empty());
+
+        // Another report accepted since keeps its watermark
+        let mut newer = advanced;
+        newer.advance(102, accepted_at + Duration::from_secs(1));
+        let mut water_mark = HashMap::from([(feed_id, newer)]);
+        restore_water_mark(&mut water_mark, feed_id, Some(previous), accepted_at);
+        assert_eq!(water_mark.get(&feed_id), Some(&newer));
+    }
+
+    #[test]
+    fn test_circuit_breaker_opens_after_consecutive_full_reconnects() {
+        let circuit = CircuitBreakerState::default();
+        assert_eq!(circuit.state(), CircuitState::Closed);
+
+        assert!(!circuit.record_full_reconnect(3));
+        assert!(!circuit.record_full_reconnect(3));
+        assert!(circuit.record_full_reconnect(3));
+
+        assert!(circuit.open());
+        assert!(!circuit.open());
+        assert_eq!(circuit.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_circuit_breaker_accepted_report_resets_failures() {
+        let circuit = CircuitBreakerState::default();
+
+        assert!(!circuit.record_full_reconnect(3));
+        assert!(!circuit.record_full_reconnect(3));
+
+        // Already closed, so no transition is reported
+        assert!(!circuit.record_accepted());
+
+        assert!(!circuit.record_full_reconnect(3));
+        assert!(!circuit.record_full_reconnect(3));
+        assert!(circuit.record_full_reconnect(3));
+    }
+
+    #[test]
+    fn test_circuit_breaker_half_open() {
+        let circuit = CircuitBreakerState::default();
+        circuit.open();
+
+        assert!(circuit.half_open());
+        assert_eq!(circuit.state(), CircuitState::HalfOpen);
+
+        // The probe dropping before any report reopens the circuit immediately
+        assert!(circuit.record_full_reconnect(100));
+
+        // A report accepted over the probe closes it
+        assert!(circuit.record_accepted());
+        assert_eq!(circuit.state(), CircuitState::Closed);
+        assert!(!circuit.record_full_reconnect(2));
+    }
+
+    #[tokio::test]
+    async fn test_check_channel_highwater() {
+        let (report_sender, mut report_receiver) = mpsc::channel(10);
+        let stats = Stats::default();
+        let report: WebSocketReport = serde_json::from_str(
+            r#"{"report":{"feedID":"0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439","validFromTimestamp":1,"observationsTimestamp":1,"fullReport":"0x"}}"#,
+        )
+        .unwrap();
+
+        for _ in 0..7 {
+            report_sender.send(report.clone()).await.unwrap();
+            check_channel_highwater(&report_sender, &stats, 0.8);
+        }
+        assert_eq!(stats.channel_highwater_events.load(Ordering::SeqCst), 0);
+
+        // Crossing the mark counts once, staying above it does not
+        for _ in 0..3 {
+            report_sender.send(report.clone()).await.unwrap();
+            check_channel_highwater(&report_sender, &stats, 0.8);
+        }
+        assert_eq!(stats.channel_highwater_events.load(Ordering::SeqCst), 1);
+
+        // Draining below the mark re-arms the warning
+        for _ in 0..5 {
+            report_receiver.recv().await.unwrap();
+        }
+        check_channel_highwater(&report_sender, &stats, 0.8);
+        assert!(!stats.channel_above_highwater.load(Ordering::SeqCst));
+
+        for _ in 0..3 {
+            report_sender.send(report.clone()).await.unwrap();
+            check_channel_highwater(&report_sender, &stats, 0.8);
+        }
+        assert_eq!(stats.channel_highwater_events.load(Ordering::SeqCst), 2);
+    }
+}
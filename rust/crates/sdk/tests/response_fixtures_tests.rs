@@ -0,0 +1,95 @@
+use chainlink_data_streams_report::feed_id::ID;
+use chainlink_data_streams_report::report::{Report, ReportData};
+use chainlink_data_streams_sdk::client::{FeedsResponse, ReportResponse, ReportsResponse};
+use chainlink_data_streams_sdk::stream::WebSocketReport;
+
+const REPORTS_LATEST: &str = include_str!("fixtures/reports_latest.json");
+const REPORTS_BULK: &str = include_str!("fixtures/reports_bulk.json");
+const FEEDS: &str = include_str!("fixtures/feeds.json");
+
+const FEED_ID_A: &str = "0x00030ab7d02fbba9c6304f98824524407b1f494741174320cfd17a2c22eec1de";
+const FEED_ID_B: &str = "0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439";
+
+#[test]
+fn test_deserialize_reports_latest() {
+    let response: ReportResponse = serde_json::from_str(REPORTS_LATEST).unwrap();
+    let report = response.report;
+
+    assert_eq!(report.feed_id, ID::from_hex_str(FEED_ID_A).unwrap());
+    assert_eq!(report.valid_from_timestamp, 1722348998);
+    assert_eq!(report.observations_timestamp, 1722348998);
+    assert!(report.full_report.starts_with("0x0006bd87"));
+
+    match report.decode_typed().unwrap() {
+        ReportData::V3(report_data) => {
+            assert_eq!(report_data.feed_id, report.feed_id);
+            assert_eq!(
+                report_data.observations_timestamp as usize,
+                report.observations_timestamp
+            );
+        }
+        other => panic!("Expected ReportData::V3, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_deserialize_reports_bulk() {
+    let response: ReportsResponse = serde_json::from_str(REPORTS_BULK).unwrap();
+
+    let feed_ids = response
+        .reports
+        .iter()
+        .map(|report| report.feed_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        feed_ids,
+        vec![
+            ID::from_hex_str(FEED_ID_A).unwrap(),
+            ID::from_hex_str(FEED_ID_B).unwrap()
+        ]
+    );
+
+    for report in &response.reports {
+        assert!(report.decode_typed().is_ok());
+    }
+}
+
+#[test]
+fn test_deserialize_feeds() {
+    let response: FeedsResponse = serde_json::from_str(FEEDS).unwrap();
+
+    assert_eq!(response.feeds.len(), 2);
+    assert_eq!(
+        response.feeds[0].feed_id,
+        ID::from_hex_str(FEED_ID_A).unwrap()
+    );
+    assert_eq!(
+        response.feeds[1].feed_id,
+        ID::from_hex_str(FEED_ID_B).unwrap()
+    );
+}
+
+#[test]
+fn test_report_serde_round_trip() {
+    let fixture: serde_json::Value = serde_json::from_str(REPORTS_LATEST).unwrap();
+
+    let report: Report = serde_json::from_value(fixture["report"].clone()).unwrap();
+
+    // Serializing must produce the same field names the server uses
+    assert_eq!(serde_json::to_value(&report).unwrap(), fixture["report"]);
+}
+
+#[test]
+fn test_convert_between_rest_and_websocket_reports() {
+    let response: ReportResponse = serde_json::from_str(REPORTS_LATEST).unwrap();
+    let expected = response.report.clone();
+
+    let websocket_report = WebSocketReport::from(response);
+    assert_eq!(websocket_report.report, expected);
+    assert!(websocket_report.raw.is_none());
+
+    // A WebSocket frame has the same shape as the REST response body
+    let websocket_report: WebSocketReport = serde_json::from_str(REPORTS_LATEST).unwrap();
+    let response = ReportResponse::from(websocket_report);
+    assert_eq!(response.report, expected);
+}
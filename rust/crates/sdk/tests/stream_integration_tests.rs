@@ -2,12 +2,20 @@
 mod mock_websocket_server;
 use mock_websocket_server::MockWebSocketServer;
 
-use chainlink_data_streams_sdk::config::{Config, WebSocketHighAvailability};
+use chainlink_data_streams_sdk::config::{
+    Config, ConfigBuilder, ConfigError, WebSocketHighAvailability,
+};
+use chainlink_data_streams_sdk::feed_id::ID;
+use chainlink_data_streams_sdk::source::ReportSource;
 use chainlink_data_streams_sdk::stream::{
-    Stream, MAX_WS_RECONNECT_INTERVAL, MIN_WS_RECONNECT_INTERVAL,
+    CircuitState, ConnectionEvent, Stream, StreamError, StreamEvent, StreamHealth,
+    MAX_WS_RECONNECT_INTERVAL, MIN_WS_RECONNECT_INTERVAL,
 };
 
+use futures::stream::{FusedStream, StreamExt};
 use std::iter::repeat;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 use tracing_subscriber::fmt::time::UtcTime;
 
@@ -16,6 +24,12 @@ const NUMBER_OF_CONNECTIONS: usize = 5;
 const MAX_RECONNECT_ATTEMPTS: usize = 10;
 
 async fn prepare_scenario() -> (MockWebSocketServer, Stream, Vec<u8>) {
+    prepare_scenario_with(|builder| builder).await
+}
+
+async fn prepare_scenario_with(
+    configure: impl FnOnce(ConfigBuilder) -> ConfigBuilder,
+) -> (MockWebSocketServer, Stream, Vec<u8>) {
     let mock_server_address = "127.0.0.1:0";
     let mock_server = MockWebSocketServer::new(mock_server_address).await;
 
@@ -25,14 +39,16 @@ async fn prepare_scenario() -> (MockWebSocketServer, Stream, Vec<u8>) {
 
     let ws_url = origins.join(",");
 
-    let config = Config::new(
-        "mock_key".to_string(),
-        "mock_secret".to_string(),
-        "mock_rest_url".to_string(),
-        ws_url,
+    let config = configure(
+        Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            "http://localhost".to_string(),
+            ws_url,
+        )
+        .with_ws_ha(WebSocketHighAvailability::Enabled)
+        .with_ws_max_reconnect(MAX_RECONNECT_ATTEMPTS),
     )
-    .with_ws_ha(WebSocketHighAvailability::Enabled)
-    .with_ws_max_reconnect(MAX_RECONNECT_ATTEMPTS)
     .build()
     .expect("Failed to build config");
 
@@ -147,7 +163,9 @@ async fn test_stream_ha_read_report() {
                     let feed_version = report.feed_id.to_hex_string()[..6].to_string();
                     assert_eq!(feed_version, "0x0003");
                 }
-                Err(_) => {}
+                Err(_) => {
+                    // A read error is not what this test checks
+                }
             }
         }
     }
@@ -155,6 +173,244 @@ async fn test_stream_ha_read_report() {
     stream.close().await.expect("Failed to close stream");
 }
 
+#[tokio::test]
+async fn test_stream_ha_read_timeout() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    let result = stream.read_timeout(Duration::from_millis(100)).await;
+    assert!(matches!(result, Ok(None)));
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    let report = stream
+        .read_timeout(Duration::from_secs(5))
+        .await
+        .expect("Failed to read report")
+        .expect("No report received before the timeout");
+    assert_eq!(report.report.feed_id.version(), 3);
+
+    stream.close().await.expect("Failed to close stream");
+}
+
+#[tokio::test]
+async fn test_stream_ha_reports_adapter() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    let reports = tokio::time::timeout(
+        Duration::from_secs(5),
+        stream.reports().take(1).collect::<Vec<_>>(),
+    )
+    .await
+    .expect("Timed out waiting for a report");
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].as_ref().unwrap().report.feed_id.version(), 3);
+
+    // Yields the error that stopped the connections, then ends
+    mock_server
+        .close_connections(1008, "policy violation")
+        .await;
+
+    let mut reports = stream.reports();
+    let result = tokio::time::timeout(Duration::from_secs(1), reports.next())
+        .await
+        .expect("Timed out waiting for the stream to stop");
+    assert!(matches!(
+        result,
+        Some(Err(StreamError::ClosedByServer { code: 1008, .. }))
+    ));
+    assert!(reports.is_terminated());
+    assert!(reports.next().await.is_none());
+    drop(reports);
+
+    // Ends without an error once the Stream is closed
+    stream.close().await.expect("Failed to close stream");
+    assert!(stream.reports().next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_stream_feed_ids() {
+    let mock_server = MockWebSocketServer::new("127.0.0.1:0").await;
+
+    let config = Config::new(
+        "mock_key".to_string(),
+        "mock_secret".to_string(),
+        "http://localhost".to_string(),
+        format!("ws://{}", mock_server.address()),
+    )
+    .build()
+    .expect("Failed to build config");
+
+    let feed_ids = vec![
+        ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439")
+            .unwrap(),
+        ID::from_hex_str("0x000359843a543ee2fe414dc14c7e7920ef10f4372990b79d6361cdc0dd1ba782")
+            .unwrap(),
+    ];
+
+    let stream = Stream::new(&config, feed_ids.clone())
+        .await
+        .expect("Failed to create stream");
+
+    assert_eq!(stream.feed_ids(), feed_ids.as_slice());
+}
+
+#[tokio::test]
+async fn test_stream_subscribe_additional() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    let feed_id =
+        ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439")
+            .unwrap();
+
+    stream
+        .subscribe_additional(vec![feed_id, feed_id])
+        .await
+        .expect("Failed to subscribe");
+    // Feeds already subscribed to don't open another connection
+    stream
+        .subscribe_additional(vec![feed_id])
+        .await
+        .expect("Failed to subscribe");
+
+    assert_eq!(stream.feed_ids(), &[feed_id]);
+
+    // Allow some time for the client to establish the connections.
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = stream.get_stats();
+    assert_eq!(stats.configured_connections, NUMBER_OF_CONNECTIONS * 2);
+    assert_eq!(stats.active_connections, NUMBER_OF_CONNECTIONS * 2);
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    let report = stream.read().await.expect("Failed to read report");
+    assert_eq!(report.report.feed_id, feed_id);
+
+    // Allow some time for the client to receive all reports.
+    sleep(Duration::from_millis(500)).await;
+
+    // The new connections share the watermarks of the existing ones
+    let stats = stream.get_stats();
+    assert_eq!(stats.accepted, 1);
+    assert_eq!(stats.deduplicated, NUMBER_OF_CONNECTIONS * 2 - 1);
+
+    stream.close().await.expect("Failed to close stream");
+}
+
+#[tokio::test]
+async fn test_stream_ha_as_report_source() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    let report = tokio::time::timeout(Duration::from_secs(1), stream.next_report())
+        .await
+        .expect("Timed out waiting for report")
+        .expect("Failed to read report")
+        .expect("Stream is exhausted");
+    assert_eq!(&report.feed_id.to_hex_string()[..6], "0x0003");
+
+    stream.close().await.expect("Failed to close stream");
+}
+
+#[tokio::test]
+async fn test_stream_ha_on_report() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    let (report_sender, mut report_receiver) = tokio::sync::mpsc::unbounded_channel();
+    stream
+        .on_report(move |response| {
+            let _ = report_sender.send(response);
+        })
+        .expect("Failed to register report handler");
+
+    // Reports now go to the handler, not to read()
+    assert!(stream.on_report(|_| {}).is_err());
+    assert!(matches!(
+        stream.read().await,
+        Err(StreamError::StreamClosed)
+    ));
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    let response = tokio::time::timeout(Duration::from_secs(1), report_receiver.recv())
+        .await
+        .expect("Timed out waiting for report")
+        .expect("Report handler stopped");
+    assert_eq!(&response.report.feed_id.to_hex_string()[..6], "0x0003");
+
+    stream.close().await.expect("Failed to close stream");
+
+    // The handler, and the sender it owns, is dropped once the task ends
+    let closed = tokio::time::timeout(Duration::from_secs(1), async {
+        while report_receiver.recv().await.is_some() {}
+    })
+    .await;
+    assert!(closed.is_ok(), "Report handler task did not terminate");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_stream_ha_close_waits_for_report_handler() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    let (started_sender, mut started_receiver) = tokio::sync::mpsc::unbounded_channel();
+    let handled = Arc::new(AtomicBool::new(false));
+    let handler_handled = handled.clone();
+    stream
+        .on_report(move |_| {
+            let _ = started_sender.send(());
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            handler_handled.store(true, Ordering::SeqCst);
+        })
+        .expect("Failed to register report handler");
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    tokio::time::timeout(Duration::from_secs(1), started_receiver.recv())
+        .await
+        .expect("Timed out waiting for report")
+        .expect("Report handler stopped");
+
+    stream.close().await.expect("Failed to close stream");
+    assert!(
+        handled.load(Ordering::SeqCst),
+        "close returned before the report handler did"
+    );
+}
+
+#[tokio::test]
+async fn test_stream_ha_retain_raw() {
+    let (mock_server, mut stream, mock_report_v3_data) =
+        prepare_scenario_with(|builder| builder.with_retain_raw(true)).await;
+
+    mock_server.send_binary(mock_report_v3_data.clone()).await;
+
+    let response = tokio::time::timeout(Duration::from_secs(1), stream.read())
+        .await
+        .expect("Timed out waiting for report")
+        .expect("Failed to read report");
+    assert_eq!(response.raw, Some(mock_report_v3_data));
+
+    stream.close().await.expect("Failed to close stream");
+}
+
+#[tokio::test]
+async fn test_stream_ha_raw_not_retained_by_default() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    let response = tokio::time::timeout(Duration::from_secs(1), stream.read())
+        .await
+        .expect("Timed out waiting for report")
+        .expect("Failed to read report");
+    assert!(response.raw.is_none());
+
+    stream.close().await.expect("Failed to close stream");
+}
+
 #[tokio::test]
 async fn test_stream_ha_graceful_shutdown() {
     let (_, mut stream, _) = prepare_scenario().await;
@@ -170,6 +426,30 @@ async fn test_stream_ha_graceful_shutdown() {
     assert_eq!(stats.active_connections, 0);
 }
 
+#[tokio::test]
+async fn test_stream_ha_close_drain() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    // Allow some time for the client to receive the report.
+    sleep(Duration::from_millis(500)).await;
+
+    let reports = stream.close_drain().await;
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].report.observations_timestamp, 1731083125);
+
+    let stats = stream.get_stats();
+    assert_eq!(stats.active_connections, 0);
+    assert_eq!(stats.buffered_bytes, 0);
+
+    assert!(matches!(
+        stream.read().await,
+        Err(StreamError::StreamClosed)
+    ));
+    assert!(stream.close_drain().await.is_empty());
+}
+
 #[tokio::test]
 async fn test_stream_ha_reconnect() {
     let (mock_server, stream, _) = prepare_scenario().await;
@@ -188,6 +468,248 @@ async fn test_stream_ha_reconnect() {
     assert_eq!(stats.partial_reconnects, expected_partial_reconnects);
 }
 
+#[tokio::test]
+async fn test_stream_ha_unanswered_ping_reconnects() {
+    // The mock server never reads from its connections, so client pings go unanswered
+    let (_mock_server, stream, _) =
+        prepare_scenario_with(|builder| builder.with_ws_ping_interval(Duration::from_millis(400)))
+            .await;
+
+    // One interval until the ping, another until the connection counts as dead, then the backoff
+    sleep(Duration::from_millis(2500)).await;
+
+    let stats = stream.get_stats();
+    assert!(stats.full_reconnects + stats.partial_reconnects >= NUMBER_OF_CONNECTIONS);
+
+    let zero_interval = Config::new(
+        "mock_key".to_string(),
+        "mock_secret".to_string(),
+        "http://localhost".to_string(),
+        "ws://localhost".to_string(),
+    )
+    .with_ws_ping_interval(Duration::ZERO)
+    .build();
+    assert!(matches!(
+        zero_interval,
+        Err(ConfigError::InvalidWsPingInterval)
+    ));
+}
+
+#[tokio::test]
+async fn test_stream_ha_health() {
+    let (mock_server, stream, _) = prepare_scenario().await;
+
+    assert_eq!(stream.health(), StreamHealth::Healthy);
+    assert!(stream.is_healthy());
+
+    mock_server.shutdown().await;
+    mock_server.drop_connections().await;
+
+    // Allow some time for the connections to drop.
+    sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(stream.health(), StreamHealth::Down);
+    assert!(!stream.is_healthy());
+}
+
+#[tokio::test]
+async fn test_reconnect_interval_validation() {
+    let builder = || {
+        Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            "http://localhost".to_string(),
+            "ws://localhost".to_string(),
+        )
+    };
+
+    assert!(builder()
+        .with_ws_reconnect_interval(Duration::from_millis(10), Duration::from_millis(10))
+        .build()
+        .is_ok());
+    assert!(builder()
+        .with_ws_reconnect_interval(Duration::ZERO, Duration::from_millis(10))
+        .build()
+        .is_err());
+    assert!(builder()
+        .with_ws_reconnect_interval(Duration::from_millis(20), Duration::from_millis(10))
+        .build()
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_url_validation() {
+    let build = |rest_url: &str, ws_url: &str| {
+        Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            rest_url.to_string(),
+            ws_url.to_string(),
+        )
+        .build()
+    };
+
+    assert!(build("https://api.example.com", "wss://ws.example.com/ws").is_ok());
+    assert!(build(
+        "http://127.0.0.1:8080",
+        "ws://127.0.0.1:8081, ws://127.0.0.1:8082"
+    )
+    .is_ok());
+    // REST-only configs may leave the WebSocket URL empty
+    assert!(build("https://api.example.com", "").is_ok());
+
+    for rest_url in ["api.example.com", "wss://api.example.com", "https://", ""] {
+        assert!(matches!(
+            build(rest_url, "wss://ws.example.com"),
+            Err(ConfigError::InvalidRestUrl(url)) if url == rest_url
+        ));
+    }
+
+    for ws_url in [
+        "ws.example.com",
+        "https://ws.example.com",
+        "wss://ws.example.com,ws.example.com",
+        "wss://ws.example.com,",
+    ] {
+        assert!(matches!(
+            build("https://api.example.com", ws_url),
+            Err(ConfigError::InvalidWsUrl(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_ha_requires_multiple_origins() {
+    let build = |ws_url: &str| {
+        Config::new(
+            "mock_key".to_string(),
+            "mock_secret".to_string(),
+            "http://localhost".to_string(),
+            ws_url.to_string(),
+        )
+        .with_ws_ha(WebSocketHighAvailability::Enabled)
+        .build()
+    };
+
+    assert!(build("ws://localhost:8081,ws://localhost:8082").is_ok());
+    assert!(matches!(
+        build("ws://localhost:8081"),
+        Err(ConfigError::HaRequiresMultipleOrigins(1))
+    ));
+    assert!(matches!(
+        build(""),
+        Err(ConfigError::HaRequiresMultipleOrigins(0))
+    ));
+}
+
+#[tokio::test]
+async fn test_stream_ha_closed_by_server() {
+    let (mock_server, mut stream, _) = prepare_scenario().await;
+
+    mock_server
+        .close_connections(1008, "policy violation")
+        .await;
+
+    let result = tokio::time::timeout(Duration::from_secs(1), stream.read())
+        .await
+        .expect("Timed out waiting for the stream to stop");
+    match result {
+        Err(StreamError::ClosedByServer { code, reason }) => {
+            assert_eq!(code, 1008);
+            assert_eq!(reason, "policy violation");
+        }
+        other => panic!("Expected ClosedByServer, got {:?}", other),
+    }
+
+    let stats = stream.get_stats();
+    assert_eq!(stats.active_connections, 0);
+    assert_eq!(stats.full_reconnects + stats.partial_reconnects, 0);
+}
+
+#[tokio::test]
+async fn test_stream_ha_reconnect_after_transient_close() {
+    let (mock_server, stream, _) = prepare_scenario().await;
+
+    mock_server.close_connections(1001, "going away").await;
+
+    // Allow some time for the client to reconnect.
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = stream.get_stats();
+    assert_eq!(stats.active_connections, NUMBER_OF_CONNECTIONS);
+    assert_eq!(
+        stats.full_reconnects + stats.partial_reconnects,
+        NUMBER_OF_CONNECTIONS
+    );
+}
+
+#[tokio::test]
+async fn test_stream_ha_circuit_breaker() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario_with(|builder| {
+        builder.with_circuit_breaker(1, Duration::from_millis(500))
+    })
+    .await;
+    let mut events = stream.subscribe_events();
+
+    mock_server.drop_connections().await;
+
+    // The full reconnect opens the circuit, the partial ones reconnect as usual.
+    sleep(Duration::from_millis(250)).await;
+
+    assert_eq!(events.try_recv().unwrap(), ConnectionEvent::CircuitOpen);
+    let stats = stream.get_stats();
+    assert_eq!(stats.circuit_state, CircuitState::Open);
+    assert_eq!(stats.active_connections, NUMBER_OF_CONNECTIONS - 1);
+
+    // After the cooldown, a single probe connection is made.
+    sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(events.try_recv().unwrap(), ConnectionEvent::CircuitHalfOpen);
+    let stats = stream.get_stats();
+    assert_eq!(stats.circuit_state, CircuitState::HalfOpen);
+    assert_eq!(stats.active_connections, NUMBER_OF_CONNECTIONS);
+
+    // An accepted report closes the circuit.
+    mock_server.send_binary(mock_report_v3_data).await;
+    stream.read().await.expect("Failed to read report");
+
+    assert_eq!(events.recv().await.unwrap(), ConnectionEvent::CircuitClosed);
+    assert_eq!(stream.get_stats().circuit_state, CircuitState::Closed);
+
+    stream.close().await.expect("Failed to close stream");
+}
+
+#[tokio::test]
+async fn test_stream_ha_max_buffered_bytes() {
+    let (mock_server, mut stream, mock_report_v3_data) =
+        prepare_scenario_with(|builder| builder.with_max_buffered_bytes(1)).await;
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    // Allow some time for the client to receive all reports.
+    sleep(Duration::from_millis(500)).await;
+
+    // The first report takes the whole budget and holds back the other connections.
+    let stats = stream.get_stats();
+    assert_eq!(stats.accepted, 1);
+    assert_eq!(stats.deduplicated, 0);
+    assert!(stats.buffered_bytes > 0);
+
+    let report = stream.read().await.expect("Failed to read report");
+    assert_eq!(stream.get_stats().buffered_bytes, 0);
+
+    // Reading frees the budget, and the held back copies are deduplicated.
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = stream.get_stats();
+    assert_eq!(stats.accepted, 1);
+    assert_eq!(stats.deduplicated, NUMBER_OF_CONNECTIONS - 1);
+    assert_eq!(stats.buffered_bytes, 0);
+    assert!(!report.report.full_report.is_empty());
+
+    stream.close().await.expect("Failed to close stream");
+}
+
 #[tokio::test]
 async fn test_stream_ha_filter_duplicate_reports() {
     let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
@@ -216,6 +738,74 @@ async fn test_stream_ha_filter_duplicate_reports() {
     assert_eq!(stats.deduplicated, expected_deduplicated);
 }
 
+#[tokio::test]
+async fn test_stream_ha_event_hook() {
+    static ACCEPTED: AtomicUsize = AtomicUsize::new(0);
+    static DEDUPLICATED: AtomicUsize = AtomicUsize::new(0);
+    static CLOSED: AtomicUsize = AtomicUsize::new(0);
+
+    fn hook(event: StreamEvent) {
+        let counter = match event {
+            StreamEvent::ReportAccepted { .. } => &ACCEPTED,
+            StreamEvent::ReportDeduplicated { .. } => &DEDUPLICATED,
+            StreamEvent::ConnectionClosed => &CLOSED,
+            StreamEvent::PartialReconnect | StreamEvent::FullReconnect => return,
+        };
+        counter.fetch_add(1, Ordering::SeqCst);
+    }
+
+    let (mock_server, mut stream, mock_report_v3_data) =
+        prepare_scenario_with(|builder| builder.with_stream_event_hook(hook)).await;
+
+    mock_server.send_binary(mock_report_v3_data).await;
+
+    // Allow some time for the client to receive all reports.
+    sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(ACCEPTED.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        DEDUPLICATED.load(Ordering::SeqCst),
+        NUMBER_OF_CONNECTIONS - 1
+    );
+
+    stream.close().await.expect("Failed to close stream");
+
+    assert_eq!(CLOSED.load(Ordering::SeqCst), NUMBER_OF_CONNECTIONS);
+}
+
+#[tokio::test]
+async fn test_stream_ha_reset_watermark() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+
+    let feed_id =
+        ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439")
+            .unwrap();
+    assert_eq!(stream.last_seen_timestamp(&feed_id).await, None);
+
+    mock_server.send_binary(mock_report_v3_data.clone()).await;
+    stream.read().await.expect("Failed to read report");
+
+    // Allow some time for the client to receive all reports.
+    sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(stream.last_seen_timestamp(&feed_id).await, Some(1731083125));
+    assert_eq!(stream.get_stats().accepted, 1);
+
+    // Once reset, the same report is accepted again
+    stream.reset_watermark().await;
+    assert_eq!(stream.last_seen_timestamp(&feed_id).await, None);
+
+    mock_server.send_binary(mock_report_v3_data).await;
+    stream.read().await.expect("Failed to read report");
+
+    sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(stream.last_seen_timestamp(&feed_id).await, Some(1731083125));
+    assert_eq!(stream.get_stats().accepted, 2);
+
+    stream.close().await.expect("Failed to close stream");
+}
+
 #[tokio::test]
 async fn test_stream_ha_reconnect_merge() {
     let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
@@ -259,6 +849,70 @@ async fn test_stream_ha_reconnect_merge() {
     assert_eq!(stats.deduplicated, expected_deduplicated);
 }
 
+/// Rewrites the observations timestamp of the mock report.
+fn with_observations_timestamp(report_data: &[u8], timestamp: usize) -> Vec<u8> {
+    String::from_utf8(report_data.to_vec())
+        .unwrap()
+        .replace(
+            r#""observationsTimestamp":1731083125"#,
+            &format!(r#""observationsTimestamp":{}"#, timestamp),
+        )
+        .into_bytes()
+}
+
+#[tokio::test]
+async fn test_stream_ha_watermark_survives_full_reconnect() {
+    let (mock_server, mut stream, mock_report_v3_data) = prepare_scenario().await;
+    let feed_id =
+        ID::from_hex_str("0x00037da06d56d083fe599397a4769a042d63aa73dc4ef57709d31e9971a5b439")
+            .unwrap();
+
+    mock_server.send_binary(mock_report_v3_data.clone()).await;
+    stream.read().await.expect("Failed to read report");
+
+    // Drop all connections, so the Stream goes through a full reconnect.
+    mock_server.drop_connections().await;
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = stream.get_stats();
+    assert!(stats.full_reconnects >= 1);
+    assert_eq!(stats.active_connections, NUMBER_OF_CONNECTIONS);
+    assert_eq!(stream.last_seen_timestamp(&feed_id).await, Some(1731083125));
+
+    // An older report replayed after the reconnect is dropped
+    mock_server
+        .send_binary(with_observations_timestamp(
+            &mock_report_v3_data,
+            1731083124,
+        ))
+        .await;
+    sleep(Duration::from_millis(500)).await;
+    assert!(stream
+        .read_timeout(Duration::from_millis(100))
+        .await
+        .expect("Failed to read report")
+        .is_none());
+
+    // A strictly newer one is accepted
+    mock_server
+        .send_binary(with_observations_timestamp(
+            &mock_report_v3_data,
+            1731083126,
+        ))
+        .await;
+    let report = stream.read().await.expect("Failed to read report");
+    assert_eq!(report.report.observations_timestamp, 1731083126);
+
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = stream.get_stats();
+    assert_eq!(stats.accepted, 2);
+    assert_eq!(stats.total_received, NUMBER_OF_CONNECTIONS * 3);
+    assert_eq!(stats.deduplicated, NUMBER_OF_CONNECTIONS * 3 - 2);
+
+    stream.close().await.expect("Failed to close stream");
+}
+
 #[tokio::test]
 #[ignore] // Ignored because it takes a while to complete. To run it, use this command: cargo test -- --ignored
 async fn test_stream_ha_max_reconnection_attempts() {
@@ -4,10 +4,14 @@ use tokio::{
     net::TcpListener,
     sync::{mpsc, Mutex, Notify},
 };
-use tokio_tungstenite::{accept_async, tungstenite::protocol::Message};
+use tokio_tungstenite::{
+    accept_async,
+    tungstenite::protocol::{frame::coding::CloseCode, CloseFrame, Message},
+};
 
 enum ServerCommand {
     Send(Vec<u8>),
+    Close(u16, String),
     DropConnections,
 }
 
@@ -93,6 +97,17 @@ impl MockWebSocketServer {
                             let _ = client.send(Message::Binary(data.clone())).await;
                         }
                     }
+                    ServerCommand::Close(code, reason) => {
+                        let mut clients = clients_command.lock().await;
+                        for client in clients.iter() {
+                            let frame = CloseFrame {
+                                code: CloseCode::from(code),
+                                reason: reason.clone().into(),
+                            };
+                            let _ = client.send(Message::Close(Some(frame))).await;
+                        }
+                        clients.clear();
+                    }
                     ServerCommand::DropConnections => {
                         println!("Dropping all client connections");
                         let mut clients = clients_command.lock().await;
@@ -117,6 +132,13 @@ impl MockWebSocketServer {
         let _ = self.command_sender.send(ServerCommand::Send(data)).await;
     }
 
+    pub async fn close_connections(&self, code: u16, reason: &str) {
+        let _ = self
+            .command_sender
+            .send(ServerCommand::Close(code, reason.to_string()))
+            .await;
+    }
+
     pub async fn drop_connections(&self) {
         let _ = self
             .command_sender